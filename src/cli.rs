@@ -5,7 +5,10 @@ use crate::{
     test_runner::tests_successful,
 };
 use clap::Parser;
-use std::{fs, path::PathBuf};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
@@ -62,7 +65,7 @@ pub fn run() -> Result<()> {
 }
 
 fn check_path(source_folder_path: &String) -> Result<PathBuf> {
-    let source_folder_path = canonicalize(&source_folder_path)?;
+    let source_folder_path = canonicalize(source_folder_path)?;
 
     if source_folder_path.is_file() {
         return Err("Path should be a folder file".into());
@@ -80,7 +83,7 @@ fn check_path(source_folder_path: &String) -> Result<PathBuf> {
     Ok(source_folder_path)
 }
 
-fn check_file(file: Option<String>, source_folder_path: &PathBuf) -> Result<Option<PathBuf>> {
+fn check_file(file: Option<String>, source_folder_path: &Path) -> Result<Option<PathBuf>> {
     if let Some(file) = file {
         let source_file_path = canonicalize(&file)?;
         if source_file_path.is_dir() {
@@ -128,10 +131,10 @@ pub fn print_result(results: Vec<MutationResult>) -> Result<()> {
         .collect::<Vec<_>>();
     println!("\t{} failures", failures.len());
 
-    if failures.len() > 0 {
-        println!("\nFailures:");
+    for (category, group) in group_by_category(&failures) {
+        println!("\n{} ({}):", category, group.len());
 
-        for failure in &failures {
+        for failure in group {
             println!("{}\n", failure);
         }
     }
@@ -148,7 +151,23 @@ pub fn print_result(results: Vec<MutationResult>) -> Result<()> {
     }
 }
 
-fn s_or_nothing<T>(arr: &Vec<T>) -> &'static str {
+// Uncategorized failures first, then each category in order of appearance
+fn group_by_category<'a>(
+    failures: &[&'a MutationResult],
+) -> Vec<(&'static str, Vec<&'a MutationResult>)> {
+    let mut groups: Vec<(&'static str, Vec<&'a MutationResult>)> = vec![("Failures", vec![])];
+    for failure in failures {
+        let category = failure.mutation().category().unwrap_or("Failures");
+        match groups.iter_mut().find(|(c, _)| *c == category) {
+            Some((_, group)) => group.push(failure),
+            None => groups.push((category, vec![failure])),
+        }
+    }
+    groups.retain(|(_, group)| !group.is_empty());
+    groups
+}
+
+fn s_or_nothing<T>(arr: &[T]) -> &'static str {
     if arr.len() > 1 {
         "s"
     } else {
@@ -178,6 +197,7 @@ fn s_or_nothing<T>(arr: &Vec<T>) -> &'static str {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{mutant::MutationType, syntax::SourceFile};
 
     #[test]
     fn test_s_or_nothing() {
@@ -188,6 +208,23 @@ mod tests {
         assert_eq!(s_or_nothing(&arr), "");
     }
 
+    #[test]
+    fn test_group_by_category() {
+        let source = "#[starknet::contract]\nmod C {\n    fn f(ref self: ContractState) {\n        self.x.write(1);\n    }\n}";
+        let file = SourceFile::new(PathBuf::from("src/lib.cairo"), source.to_string());
+        let results: Vec<MutationResult> = [MutationType::FunctionBody, MutationType::StorageWrite]
+            .iter()
+            .flat_map(|m| m.others(&file))
+            .map(MutationResult::Failure)
+            .collect();
+        let failures: Vec<&MutationResult> = results.iter().collect();
+        let groups = group_by_category(&failures);
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].0, "Failures");
+        assert_eq!(groups[1].0, "Unobserved state changes");
+        assert_eq!(groups[1].1.len(), 1);
+    }
+
     #[test]
     fn test_check_path() {
         let path = "./test_data/assert/".to_string();
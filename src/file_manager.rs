@@ -1,8 +1,6 @@
-use crate::{Error, Result};
+use crate::{lexer::Span, Error, Result};
 use std::{
-    env,
-    fs::{self, File},
-    io::{BufRead, BufReader, Write},
+    env, fs,
     path::{Path, PathBuf},
 };
 
@@ -39,11 +37,11 @@ pub fn copy_cairo_project(src: &Path, dst: &Path) -> Result<()> {
         fs::create_dir_all(dst)?;
     }
 
-    fs::copy(&src.join("Scarb.toml"), &dst.join("Scarb.toml"))?;
-    fs::copy(&src.join("Scarb.lock"), &dst.join("Scarb.lock"))?;
+    fs::copy(src.join("Scarb.toml"), dst.join("Scarb.toml"))?;
+    fs::copy(src.join("Scarb.lock"), dst.join("Scarb.lock"))?;
 
     if src.join(".tool-versions").exists() {
-        fs::copy(&src.join(".tool-versions"), &dst.join(".tool-versions"))?;
+        fs::copy(src.join(".tool-versions"), dst.join(".tool-versions"))?;
     }
 
     copy_all_cairo(&src.join("src"), &dst.join("src"))?;
@@ -66,12 +64,10 @@ fn copy_all_cairo(src: &Path, dst: &Path) -> Result<()> {
         if path.is_dir() {
             let dest_path = dst.join(path.file_name().unwrap());
             copy_all_cairo(&path, &dest_path)?;
-        } else {
-            if let Some(ext) = path.extension() {
-                if ext == "cairo" {
-                    let dest_file = dst.join(path.file_name().unwrap());
-                    fs::copy(&path, &dest_file)?;
-                }
+        } else if let Some(ext) = path.extension() {
+            if ext == "cairo" {
+                let dest_file = dst.join(path.file_name().unwrap());
+                fs::copy(&path, &dest_file)?;
             }
         }
     }
@@ -79,29 +75,19 @@ fn copy_all_cairo(src: &Path, dst: &Path) -> Result<()> {
     Ok(())
 }
 
-pub fn change_line_content(file_path: &Path, line_number: usize, new_content: &str) -> Result<()> {
-    // Open the file for reading
-    let file = File::open(file_path)?;
-    let reader = BufReader::new(file);
-
-    // Collect lines into a vector
-    let mut lines: Vec<String> = reader.lines().map(|line| line.unwrap()).collect();
-
-    // Check if line_number is valid
-    if line_number > 0 && line_number <= lines.len() {
-        // Modify the content of the specified line
-        lines[line_number - 1] = new_content.to_string(); // line_number is 1-based
+pub fn change_file_content(file_path: &Path, span: Span, new_content: &str) -> Result<()> {
+    let mut content = fs::read_to_string(file_path)?;
 
-        // Re-open the file for writing
-        let mut file = File::create(file_path)?;
-
-        // Write the modified lines back to the file
-        for line in &lines {
-            writeln!(file, "{}", line)?;
-        }
-    } else {
-        return Err("Invalid line number".into());
+    // Check if the span is valid
+    if span.start > span.end
+        || span.end > content.len()
+        || !content.is_char_boundary(span.start)
+        || !content.is_char_boundary(span.end)
+    {
+        return Err("Invalid span".into());
     }
+    content.replace_range(span.start..span.end, new_content);
+    fs::write(file_path, content)?;
 
     Ok(())
 }
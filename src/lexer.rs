@@ -0,0 +1,180 @@
+// Lossless tokenizer for Cairo, concatenating every token's text gives back the source
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Span { start, end }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Whitespace,
+    Comment,
+    Ident,
+    Number,
+    ShortString,
+    String,
+    Punct,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+}
+
+impl Token {
+    pub fn is_trivia(&self) -> bool {
+        matches!(self.kind, TokenKind::Whitespace | TokenKind::Comment)
+    }
+
+    pub fn text<'a>(&self, source: &'a str) -> &'a str {
+        &source[self.span.start..self.span.end]
+    }
+}
+
+// Longest first, `<<` and `>>` are left as two tokens to not break generics
+const PUNCTS: [&str; 15] = [
+    "::", "->", "=>", "==", "!=", ">=", "<=", "&&", "||", "+=", "-=", "*=", "/=", "%=", "..",
+];
+
+pub fn tokenize(source: &str) -> Vec<Token> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        let start = idx;
+        let c = bytes[idx];
+        let kind = if c.is_ascii_whitespace() {
+            while idx < bytes.len() && bytes[idx].is_ascii_whitespace() {
+                idx += 1;
+            }
+            TokenKind::Whitespace
+        } else if source[idx..].starts_with("//") {
+            while idx < bytes.len() && bytes[idx] != b'\n' {
+                idx += 1;
+            }
+            TokenKind::Comment
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            idx = eat_word(bytes, idx);
+            TokenKind::Ident
+        } else if c.is_ascii_digit() {
+            idx = eat_word(bytes, idx);
+            TokenKind::Number
+        } else if c == b'\'' {
+            idx = eat_quoted(bytes, idx, b'\'');
+            // Optional type suffix, e.g. 'abc'_felt252
+            if idx < bytes.len() && bytes[idx] == b'_' {
+                idx = eat_word(bytes, idx);
+            }
+            TokenKind::ShortString
+        } else if c == b'"' {
+            idx = eat_quoted(bytes, idx, b'"');
+            TokenKind::String
+        } else if let Some(punct) = PUNCTS.iter().find(|p| source[idx..].starts_with(*p)) {
+            idx += punct.len();
+            TokenKind::Punct
+        } else if c.is_ascii_punctuation() {
+            idx += 1;
+            TokenKind::Punct
+        } else {
+            // Keep multi-byte chars whole so spans stay on char boundaries
+            idx += source[idx..].chars().next().map_or(1, |ch| ch.len_utf8());
+            TokenKind::Unknown
+        };
+        tokens.push(Token {
+            kind,
+            span: Span::new(start, idx),
+        });
+    }
+    tokens
+}
+
+fn eat_word(bytes: &[u8], mut idx: usize) -> usize {
+    while idx < bytes.len() && (bytes[idx].is_ascii_alphanumeric() || bytes[idx] == b'_') {
+        idx += 1;
+    }
+    idx
+}
+
+fn eat_quoted(bytes: &[u8], mut idx: usize, quote: u8) -> usize {
+    idx += 1;
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'\\' => idx += 2,
+            c if c == quote => return idx + 1,
+            _ => idx += 1,
+        }
+    }
+    bytes.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(source: &str) -> Vec<&str> {
+        tokenize(source)
+            .iter()
+            .filter(|t| !t.is_trivia())
+            .map(|t| t.text(source))
+            .collect()
+    }
+
+    #[test]
+    fn test_lossless() {
+        let source = "fn a(x: u8) -> bool { // c\n    x >= 1_u8 && 'a == b' != \"é\" }";
+        let rebuilt: String = tokenize(source).iter().map(|t| t.text(source)).collect();
+        assert_eq!(rebuilt, source);
+    }
+
+    #[test]
+    fn test_tokens() {
+        assert_eq!(
+            texts("fn a() -> Array<u8> { a == b }"),
+            vec!["fn", "a", "(", ")", "->", "Array", "<", "u8", ">", "{", "a", "==", "b", "}"]
+        );
+        assert_eq!(
+            texts("assert!(x, 'a == b');"),
+            vec!["assert", "!", "(", "x", ",", "'a == b'", ")", ";"]
+        );
+        // `->` and `-=` are single tokens, a lone `-` is told unary or binary by the syntax layer
+        assert_eq!(
+            texts("fn a() -> i8 { b -= -c - 1; b }"),
+            vec![
+                "fn", "a", "(", ")", "->", "i8", "{", "b", "-=", "-", "c", "-", "1", ";", "b", "}"
+            ]
+        );
+        assert_eq!(
+            texts("0.is_zero() // a == b"),
+            vec!["0", ".", "is_zero", "(", ")"]
+        );
+    }
+
+    #[test]
+    fn test_kinds() {
+        let source = "'it''s' \"a\\\"b\" 0x1f_u128";
+        let kinds: Vec<TokenKind> = tokenize(source)
+            .iter()
+            .filter(|t| !t.is_trivia())
+            .map(|t| t.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TokenKind::ShortString,
+                TokenKind::ShortString,
+                TokenKind::String,
+                TokenKind::Number
+            ]
+        );
+    }
+}
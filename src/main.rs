@@ -5,8 +5,10 @@ pub use error::{Error, Result};
 
 pub mod cli;
 pub mod file_manager;
+pub mod lexer;
 pub mod mutant;
 pub mod runner;
+pub mod syntax;
 pub mod test_runner;
 
 fn main() {
@@ -1,6 +1,10 @@
 use colored::Colorize;
 
-use crate::file_manager::{change_line_content, copy_cairo_project};
+use crate::{
+    file_manager::{change_file_content, copy_cairo_project},
+    lexer::{Span, TokenKind},
+    syntax::{Arm, SourceFile},
+};
 use std::{
     fmt,
     path::{Path, PathBuf},
@@ -20,11 +24,85 @@ pub enum MutationType {
     IsNonZero,
     And,
     Or,
-    // assert!(), assert_eq!(), etc
-    // +=, *=
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+    AddAssign,
+    SubAssign,
+    MulAssign,
+    DivAssign,
+    Assign,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
+    LeftOperand,
+    Not,
+    Removed,
+    Condition,
+    Negated,
+    True,
+    False,
+    FunctionBody,
+    DefaultValue,
+    StorageWrite,
+    Emit,
+    AccessControl,
+    CallerAddress,
+    ContractAddress,
+    BlockTimestamp,
+    BlockNumber,
+    ZeroValue,
+    PlusOne,
+    IntegerLiteral,
+    MinusOne,
+    TypeMax,
+    AssertMacro,
+    AssertEq,
+    AssertNe,
+    AssertLt,
+    AssertLe,
+    AssertGt,
+    AssertGe,
+    OptionSome,
+    OptionNone,
+    ResultOk,
+    ResultErr,
+    Unwrap,
+    UnwrapOrDefault,
+    IfElse,
+    SwappedBranches,
+    MatchArm,
+    SwappedArms,
+    WildcardBody,
+    Break,
+    Continue,
+    LoopStep,
+    DoubleStep,
+    LoopBound,
+    EarlyReturn,
+    Return,
+    ErrorPropagation,
+    Expect,
+    ErrorMessage,
+    ChoppedMessage,
+    DispatcherCall,
+    DispatcherAddress,
+    TryInto,
+    Truncated,
+    Low,
+    High,
+    Append,
+    Index,
+    Len,
+    PopFront,
+    UnaryMinus,
+    SignedValue,
+    Abs,
     // Move default value
-    // Comment event
-    // Chop assert message?
 }
 
 #[derive(Debug)]
@@ -39,24 +117,44 @@ pub struct Mutation {
     from: MutationType,
     to: MutationType,
     file_name: PathBuf,
+    // Bytes of the file replaced by the mutation
+    span: Span,
+    replacement: String,
+    function: Option<String>,
+    // Full original lines covering the span, starting at byte `line_start`
     line: String,
+    line_start: usize,
     pos: usize,
+    col: usize,
 }
 
 impl fmt::Display for Mutation {
     // This trait requires `fmt` with this exact signature.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "{}:{}", self.file_name.to_str().unwrap(), self.pos + 1,).unwrap();
-
-        writeln!(f, "{} {}", "- ".red(), self.line.red()).unwrap();
-        writeln!(
+        write!(
             f,
-            "{} {}",
-            "+ ".green(),
-            self.line
-                .replace(self.from.as_str(), self.to.as_str())
-                .green()
+            "{}:{}:{}",
+            self.file_name.to_str().unwrap(),
+            self.pos + 1,
+            self.col + 1
         )
+        .unwrap();
+        if let Some(function) = &self.function {
+            write!(f, " in {}", function).unwrap();
+        }
+        write!(f, " ({:?} -> {:?})", self.from, self.to).unwrap();
+        if self.from.is_high_severity() {
+            write!(f, " {}", "[HIGH]".red().bold()).unwrap();
+        }
+        writeln!(f).unwrap();
+
+        for line in self.line.lines() {
+            writeln!(f, "{} {}", "- ".red(), line.red()).unwrap();
+        }
+        for line in self.mutated_line().lines() {
+            writeln!(f, "{} {}", "+ ".green(), line.green()).unwrap();
+        }
+        Ok(())
     }
 }
 
@@ -71,182 +169,1799 @@ impl fmt::Display for MutationResult {
     }
 }
 
+impl MutationResult {
+    pub fn mutation(&self) -> &Mutation {
+        match self {
+            MutationResult::Success(mutation)
+            | MutationResult::BuildFailure(mutation)
+            | MutationResult::Failure(mutation) => mutation,
+        }
+    }
+}
+
 impl MutationType {
     fn as_str(&self) -> &str {
         match self {
             MutationType::Equal => "==",
             MutationType::NotEqual => "!=",
-            MutationType::GreaterThan => " > ", // TODO fix: should ignore if it is "->"
+            MutationType::GreaterThan => ">",
             MutationType::GreaterThanOrEqual => ">=",
-            MutationType::LessThan => " < ",
+            MutationType::LessThan => "<",
             MutationType::LessThanOrEqual => "<=",
-            MutationType::Assert => "assert(",
-            MutationType::Comment => "//",
-            MutationType::IsZero => "is_zero()",
-            MutationType::IsNonZero => "is_non_zero()",
-            MutationType::And => " && ",
-            MutationType::Or => " || ",
+            MutationType::Assert => "assert",
+            MutationType::Comment => "",
+            MutationType::IsZero => "is_zero",
+            MutationType::IsNonZero => "is_non_zero",
+            MutationType::And => "&&",
+            MutationType::Or => "||",
+            MutationType::Add => "+",
+            MutationType::Sub => "-",
+            MutationType::Mul => "*",
+            MutationType::Div => "/",
+            MutationType::Mod => "%",
+            MutationType::AddAssign => "+=",
+            MutationType::SubAssign => "-=",
+            MutationType::MulAssign => "*=",
+            MutationType::DivAssign => "/=",
+            MutationType::Assign => "=",
+            MutationType::BitAnd => "&",
+            MutationType::BitOr => "|",
+            MutationType::BitXor => "^",
+            MutationType::ShiftLeft => "<<",
+            MutationType::ShiftRight => ">>",
+            MutationType::LeftOperand => "",
+            MutationType::Not => "!",
+            MutationType::Removed => "",
+            MutationType::Condition => "if",
+            MutationType::Negated => "!",
+            MutationType::True => "true",
+            MutationType::False => "false",
+            MutationType::FunctionBody => "fn",
+            MutationType::DefaultValue => "",
+            MutationType::StorageWrite => "self",
+            MutationType::Emit => "self",
+            MutationType::AccessControl => "assert_only",
+            MutationType::CallerAddress => "get_caller_address",
+            MutationType::ContractAddress => "get_contract_address",
+            MutationType::BlockTimestamp => "get_block_timestamp",
+            MutationType::BlockNumber => "get_block_number",
+            MutationType::ZeroValue => "0",
+            MutationType::PlusOne => "+ 1",
+            MutationType::IntegerLiteral => "",
+            MutationType::MinusOne => "- 1",
+            MutationType::TypeMax => "",
+            MutationType::AssertMacro => "assert",
+            MutationType::AssertEq => "assert_eq",
+            MutationType::AssertNe => "assert_ne",
+            MutationType::AssertLt => "assert_lt",
+            MutationType::AssertLe => "assert_le",
+            MutationType::AssertGt => "assert_gt",
+            MutationType::AssertGe => "assert_ge",
+            MutationType::OptionSome => "Some",
+            MutationType::OptionNone => "None",
+            MutationType::ResultOk => "Ok",
+            MutationType::ResultErr => "Err",
+            MutationType::Unwrap => "unwrap",
+            MutationType::UnwrapOrDefault => "unwrap_or_default",
+            MutationType::IfElse => "if",
+            MutationType::SwappedBranches => "",
+            MutationType::MatchArm => "match",
+            MutationType::SwappedArms => "",
+            MutationType::WildcardBody => "",
+            MutationType::Break => "break",
+            MutationType::Continue => "continue",
+            MutationType::LoopStep => "+=",
+            MutationType::DoubleStep => "",
+            MutationType::LoopBound => "",
+            MutationType::EarlyReturn => "fn",
+            MutationType::Return => "return",
+            MutationType::ErrorPropagation => "?",
+            MutationType::Expect => "expect",
+            MutationType::ErrorMessage => "",
+            MutationType::ChoppedMessage => "",
+            MutationType::DispatcherCall => "",
+            MutationType::DispatcherAddress => "contract_address",
+            MutationType::TryInto => "try_into",
+            MutationType::Truncated => "& 0x7f",
+            MutationType::Low => "low",
+            MutationType::High => "high",
+            MutationType::Append => "append",
+            MutationType::Index => "",
+            MutationType::Len => "len",
+            MutationType::PopFront => "pop_front",
+            MutationType::UnaryMinus => "-",
+            MutationType::SignedValue => "",
+            MutationType::Abs => "",
         }
     }
 
-    pub fn others(&self, file_name: PathBuf, line: String, pos: usize) -> Vec<Mutation> {
-        let self_idx = line.find(self.as_str());
-        if self_idx.is_none() {
-            return vec![];
-        }
-        let self_idx = self_idx.unwrap();
-        // Hopefully all line should be < 1000000000000
-        let comment_idx = line.find("//").unwrap_or(1000000000000);
-        // If the mutation is in a comment, ignore it
-        if comment_idx < self_idx {
-            // println!("Mutation found in a comment: {}", line);
-            return vec![];
+    // Label grouping the surviving mutations in the summary
+    pub fn category(&self) -> Option<&'static str> {
+        match self {
+            MutationType::StorageWrite => Some("Unobserved state changes"),
+            MutationType::Emit => Some("Events not asserted"),
+            MutationType::AccessControl => Some("Access control guards not tested"),
+            MutationType::ErrorMessage => Some("Panic messages not asserted"),
+            MutationType::DispatcherCall => Some("Cross-contract effects not verified"),
+            _ => None,
         }
+    }
+
+    // Surviving mutations that most likely hide a vulnerability
+    pub fn is_high_severity(&self) -> bool {
+        matches!(self, MutationType::AccessControl)
+    }
 
+    // Token indexes where this mutation can be applied
+    fn sites(&self, file: &SourceFile) -> Vec<usize> {
+        (0..file.code_len())
+            .filter(|&idx| match self {
+                MutationType::ShiftLeft | MutationType::ShiftRight => {
+                    file.shift_at(idx) == Some(self.as_str())
+                }
+                MutationType::Condition => matches!(file.text(idx), "if" | "assert"),
+                MutationType::IntegerLiteral => file.kind(idx) == TokenKind::Number,
+                MutationType::AccessControl => matches!(file.text(idx), "self" | "assert"),
+                MutationType::LoopBound => matches!(file.text(idx), "<" | "=="),
+                MutationType::Index => matches!(file.text(idx), "at" | "["),
+                MutationType::SignedValue => true,
+                MutationType::Abs => matches!(file.text(idx), "if" | "abs"),
+                MutationType::ErrorMessage => file.kind(idx) == TokenKind::ShortString,
+                MutationType::DispatcherCall => file.kind(idx) == TokenKind::Ident,
+                _ => file.text(idx) == self.as_str(),
+            })
+            .filter(|&idx| match self {
+                MutationType::GreaterThan | MutationType::LessThan => {
+                    !file.is_generic(idx) && !file.is_shift(idx)
+                }
+                MutationType::IsZero | MutationType::IsNonZero => file.is(idx + 1, "("),
+                MutationType::Assert => file.is(idx + 1, "("),
+                // Prefix `!`, not the one of a macro call
+                MutationType::Not => !file.is_binary(idx),
+                MutationType::Condition => file.condition(idx).is_some(),
+                MutationType::IfElse => file.branches(idx).is_some(),
+                MutationType::MatchArm => file.arms(idx).is_some_and(|arms| arms.len() > 1),
+                MutationType::Break => break_removal(file, idx).is_some(),
+                MutationType::Continue => file.kind(idx) == TokenKind::Ident,
+                MutationType::LoopStep => {
+                    file.in_loop(idx)
+                        && file.is(idx + 2, ";")
+                        && step_of_one(file.text(idx + 1)).is_some()
+                }
+                MutationType::LoopBound => loop_bound(file, idx).is_some(),
+                MutationType::True | MutationType::False => file.kind(idx) == TokenKind::Ident,
+                MutationType::FunctionBody | MutationType::EarlyReturn => {
+                    file.function_of(idx).is_some_and(|f| !f.is_test)
+                }
+                MutationType::Return => return_removal(file, idx).is_some(),
+                MutationType::ErrorPropagation => file.is_binary(idx),
+                MutationType::DispatcherCall => dispatcher_call(file, idx).is_some(),
+                MutationType::DispatcherAddress => dispatcher_address(file, idx).is_some(),
+                MutationType::TryInto => {
+                    idx > 0
+                        && file.is_sequence(idx + 1, &["(", ")", ".", "unwrap", "(", ")"])
+                        && file.receiver_start(idx - 1).is_some()
+                }
+                MutationType::Append => append(file, idx).is_some(),
+                // Prefix `-`, not a subtraction
+                MutationType::UnaryMinus => !file.is_binary(idx),
+                MutationType::SignedValue => signed_value(file, idx).is_some(),
+                MutationType::Abs => abs(file, idx).is_some(),
+                MutationType::Index => index_argument(file, idx).is_some(),
+                MutationType::Len | MutationType::PopFront => {
+                    idx > 0
+                        && file.is_sequence(idx + 1, &["(", ")"])
+                        && file.receiver_start(idx - 1).is_some()
+                }
+                // Fields of a `u256`, not methods
+                MutationType::Low | MutationType::High => {
+                    idx > 0 && file.text(idx - 1) == "." && !file.is(idx + 1, "(")
+                }
+                MutationType::ErrorMessage => {
+                    is_error_message(file, idx) && chop(file.text(idx)).is_some()
+                }
+                MutationType::Expect => {
+                    idx > 0 && file.text(idx - 1) == "." && file.is(idx + 1, "(")
+                }
+                MutationType::Emit => emit(file, idx).is_some(),
+                MutationType::AccessControl => access_guard(file, idx).is_some(),
+                MutationType::AssertMacro
+                | MutationType::AssertEq
+                | MutationType::AssertNe
+                | MutationType::AssertLt
+                | MutationType::AssertLe
+                | MutationType::AssertGt
+                | MutationType::AssertGe => file.is_sequence(idx + 1, &["!", "("]),
+                MutationType::OptionSome | MutationType::ResultOk => {
+                    variant_call(file, idx).is_some()
+                }
+                MutationType::Unwrap => {
+                    idx > 0 && file.text(idx - 1) == "." && file.is_sequence(idx + 1, &["(", ")"])
+                }
+                // Tuple fields, attributes, match arm patterns and test constants are left alone
+                MutationType::IntegerLiteral => {
+                    let tuple_field = idx > 0 && file.text(idx - 1) == ".";
+                    let test_const = file
+                        .const_name(idx)
+                        .is_some_and(|name| file.used_only_in_tests(name));
+                    !tuple_field
+                        && !test_const
+                        && !file.in_attribute(idx)
+                        && !file.in_arm_pattern(idx)
+                }
+                MutationType::CallerAddress
+                | MutationType::ContractAddress
+                | MutationType::BlockTimestamp
+                | MutationType::BlockNumber => file.is_sequence(idx + 1, &["(", ")"]),
+                MutationType::StorageWrite => {
+                    file.in_contract(file.tokens[idx].span.start)
+                        && storage_write(file, idx).is_some()
+                }
+                MutationType::Add
+                | MutationType::Sub
+                | MutationType::Mul
+                | MutationType::Div
+                | MutationType::Mod
+                | MutationType::BitAnd
+                | MutationType::BitOr
+                | MutationType::BitXor
+                | MutationType::ShiftLeft
+                | MutationType::ShiftRight => file.is_binary(idx),
+                _ => file.kind(idx) == TokenKind::Punct,
+            })
+            .collect()
+    }
+
+    fn targets(&self) -> Vec<MutationType> {
         match self {
-            MutationType::Equal => vec![Mutation {
-                from: self.clone(),
-                to: MutationType::NotEqual,
-                file_name,
-                line,
-                pos,
-            }],
-            MutationType::NotEqual => vec![Mutation {
-                from: self.clone(),
-                to: MutationType::Equal,
-                file_name,
-                line,
-                pos,
-            }],
+            MutationType::Equal => vec![MutationType::NotEqual],
+            MutationType::NotEqual => vec![MutationType::Equal],
             MutationType::GreaterThan => {
-                vec![
-                    Mutation {
-                        from: self.clone(),
-                        to: MutationType::GreaterThanOrEqual,
-                        file_name: file_name.clone(),
-                        line: line.clone(),
-                        pos,
-                    },
-                    Mutation {
-                        from: self.clone(),
-                        to: MutationType::LessThan,
-                        file_name,
-                        line,
-                        pos,
-                    },
-                ]
+                vec![MutationType::GreaterThanOrEqual, MutationType::LessThan]
             }
             MutationType::GreaterThanOrEqual => {
-                vec![
-                    Mutation {
-                        from: self.clone(),
-                        to: MutationType::Equal,
-                        file_name: file_name.clone(),
-                        line: line.clone(),
-                        pos,
-                    },
-                    Mutation {
-                        from: self.clone(),
-                        to: MutationType::GreaterThan,
-                        file_name,
-                        line,
-                        pos,
-                    },
-                ]
+                vec![MutationType::Equal, MutationType::GreaterThan]
             }
             MutationType::LessThan => {
-                vec![
-                    Mutation {
-                        from: self.clone(),
-                        to: MutationType::LessThanOrEqual,
-                        file_name: file_name.clone(),
-                        line: line.clone(),
-                        pos,
-                    },
-                    Mutation {
-                        from: self.clone(),
-                        to: MutationType::GreaterThan,
-                        file_name,
-                        line,
-                        pos,
-                    },
-                ]
+                vec![MutationType::LessThanOrEqual, MutationType::GreaterThan]
+            }
+            MutationType::LessThanOrEqual => vec![MutationType::Equal, MutationType::LessThan],
+            MutationType::Assert => vec![MutationType::Comment],
+            MutationType::Comment => {
+                panic!("Comment mutation should not be used");
+            }
+            MutationType::IsZero => vec![MutationType::IsNonZero],
+            MutationType::IsNonZero => vec![MutationType::IsZero],
+            MutationType::And => vec![MutationType::Or],
+            MutationType::Or => vec![MutationType::And],
+            MutationType::Add => vec![MutationType::Sub, MutationType::Mul],
+            MutationType::Sub => vec![MutationType::Add],
+            MutationType::Mul => vec![MutationType::Add, MutationType::Div],
+            MutationType::Div => vec![MutationType::Mul, MutationType::Mod],
+            MutationType::Mod => vec![MutationType::Div],
+            MutationType::AddAssign => vec![MutationType::SubAssign, MutationType::Assign],
+            MutationType::SubAssign => vec![MutationType::AddAssign],
+            MutationType::MulAssign => vec![MutationType::DivAssign],
+            MutationType::DivAssign => vec![MutationType::MulAssign],
+            MutationType::Assign => {
+                panic!("Assign mutation should not be used");
+            }
+            MutationType::BitAnd => vec![MutationType::BitOr],
+            MutationType::BitOr => vec![MutationType::BitAnd],
+            MutationType::BitXor => vec![MutationType::LeftOperand],
+            MutationType::ShiftLeft => vec![MutationType::ShiftRight],
+            MutationType::ShiftRight => vec![MutationType::ShiftLeft],
+            MutationType::LeftOperand => {
+                panic!("LeftOperand mutation should not be used");
             }
-            MutationType::LessThanOrEqual => {
+            MutationType::Not => vec![MutationType::Removed],
+            MutationType::Removed => {
+                panic!("Removed mutation should not be used");
+            }
+            MutationType::Condition => vec![MutationType::Negated],
+            MutationType::Negated => {
+                panic!("Negated mutation should not be used");
+            }
+            MutationType::True => vec![MutationType::False],
+            MutationType::False => vec![MutationType::True],
+            MutationType::FunctionBody => vec![MutationType::DefaultValue],
+            MutationType::DefaultValue => {
+                panic!("DefaultValue mutation should not be used");
+            }
+            MutationType::StorageWrite | MutationType::Emit | MutationType::AccessControl => {
+                vec![MutationType::Removed]
+            }
+            MutationType::CallerAddress => vec![MutationType::ContractAddress],
+            MutationType::ContractAddress => vec![MutationType::CallerAddress],
+            MutationType::BlockTimestamp | MutationType::BlockNumber => {
+                vec![MutationType::ZeroValue, MutationType::PlusOne]
+            }
+            MutationType::IntegerLiteral => {
                 vec![
-                    Mutation {
-                        from: self.clone(),
-                        to: MutationType::Equal,
-                        file_name: file_name.clone(),
-                        line: line.clone(),
-                        pos,
-                    },
-                    Mutation {
-                        from: self.clone(),
-                        to: MutationType::LessThan,
-                        file_name,
-                        line,
-                        pos,
-                    },
+                    MutationType::PlusOne,
+                    MutationType::MinusOne,
+                    MutationType::TypeMax,
                 ]
             }
+            MutationType::AssertMacro => vec![MutationType::Removed],
+            MutationType::AssertEq => vec![MutationType::AssertNe, MutationType::Removed],
+            MutationType::AssertNe => vec![MutationType::AssertEq, MutationType::Removed],
+            MutationType::AssertLt => vec![MutationType::AssertLe, MutationType::Removed],
+            MutationType::AssertLe => vec![MutationType::AssertLt, MutationType::Removed],
+            MutationType::AssertGt => vec![MutationType::AssertGe, MutationType::Removed],
+            MutationType::AssertGe => vec![MutationType::AssertGt, MutationType::Removed],
+            MutationType::OptionSome => vec![MutationType::OptionNone],
+            MutationType::ResultOk => vec![MutationType::ResultErr],
+            MutationType::Unwrap => vec![MutationType::UnwrapOrDefault],
+            MutationType::IfElse => vec![
+                MutationType::True,
+                MutationType::False,
+                MutationType::Removed,
+                MutationType::SwappedBranches,
+            ],
+            MutationType::MatchArm => vec![
+                MutationType::Removed,
+                MutationType::SwappedArms,
+                MutationType::WildcardBody,
+            ],
+            MutationType::Break => vec![MutationType::Removed],
+            MutationType::EarlyReturn => vec![MutationType::DefaultValue],
+            MutationType::Return => vec![MutationType::Removed],
+            MutationType::ErrorPropagation | MutationType::Expect => {
+                vec![MutationType::UnwrapOrDefault]
+            }
+            MutationType::ErrorMessage => vec![MutationType::ChoppedMessage],
+            MutationType::DispatcherCall => vec![MutationType::Removed],
+            MutationType::DispatcherAddress => vec![MutationType::ContractAddress],
+            MutationType::TryInto => vec![MutationType::ZeroValue, MutationType::Truncated],
+            MutationType::Low => vec![MutationType::High],
+            MutationType::High => vec![MutationType::Low],
+            MutationType::Append | MutationType::PopFront => vec![MutationType::Removed],
+            MutationType::Index => vec![MutationType::PlusOne],
+            MutationType::Len => vec![MutationType::MinusOne],
+            MutationType::UnaryMinus => vec![MutationType::Removed],
+            MutationType::SignedValue => vec![MutationType::Negated],
+            MutationType::Abs => vec![MutationType::Removed, MutationType::Negated],
+            MutationType::Continue => vec![MutationType::Break],
+            MutationType::LoopStep => vec![MutationType::DoubleStep],
+            MutationType::LoopBound => vec![
+                MutationType::LessThanOrEqual,
+                MutationType::GreaterThan,
+                MutationType::LessThan,
+            ],
+            MutationType::ZeroValue
+            | MutationType::PlusOne
+            | MutationType::MinusOne
+            | MutationType::TypeMax
+            | MutationType::OptionNone
+            | MutationType::ResultErr
+            | MutationType::UnwrapOrDefault
+            | MutationType::SwappedBranches
+            | MutationType::SwappedArms
+            | MutationType::WildcardBody
+            | MutationType::DoubleStep
+            | MutationType::ChoppedMessage
+            | MutationType::Truncated => {
+                panic!("{:?} mutation should not be used", self);
+            }
+        }
+    }
+
+    // First and last tokens replaced at a site, with the replacement if the target alone
+    // doesn't give it
+    fn site_span(&self, file: &SourceFile, idx: usize) -> Option<(usize, usize, Option<String>)> {
+        match self {
             MutationType::Assert => {
-                // Only support one line assert for now
-                if line.chars().last().unwrap() != ';' {
+                let close = file.matching(idx + 1)?;
+                let (last, replacement) = file.removal(idx, close)?;
+                Some((idx, last, Some(replacement.to_string())))
+            }
+            MutationType::StorageWrite | MutationType::Emit | MutationType::AccessControl => {
+                let close = match self {
+                    MutationType::StorageWrite => storage_write(file, idx)?,
+                    MutationType::Emit => emit(file, idx)?,
+                    _ => access_guard(file, idx)?,
+                };
+                let (last, replacement) = file.removal(idx, close)?;
+                Some((idx, last, Some(replacement.to_string())))
+            }
+            // Drop the operator and its right operand
+            MutationType::BitXor => Some((idx, file.right_operand_end(idx)?, None)),
+            MutationType::ShiftLeft | MutationType::ShiftRight => Some((idx, idx + 1, None)),
+            MutationType::Condition => {
+                let (first, last) = file.condition(idx)?;
+                let single = file.operand_end(first) == Some(last);
+                // Already negated, `Not` removes the `!`
+                if single && file.text(first) == "!" {
+                    return None;
+                }
+                let condition = file.slice(file.span(first, last));
+                let negated = if single {
+                    format!("!{}", condition)
+                } else {
+                    format!("!({})", condition)
+                };
+                Some((first, last, Some(negated)))
+            }
+            // The default value instead of propagating the error or panicking
+            MutationType::ErrorPropagation => {
+                let replacement = format!(".{}()", MutationType::UnwrapOrDefault.as_str());
+                Some((idx, idx, Some(replacement)))
+            }
+            MutationType::ErrorMessage => Some((idx, idx, Some(chop(file.text(idx))?))),
+            MutationType::SignedValue => {
+                let last = signed_value(file, idx)?;
+                Some((idx, last, Some(negate(file, idx, last))))
+            }
+            MutationType::Append => {
+                let (first, close) = append(file, idx)?;
+                let (last, replacement) = file.removal(first, close)?;
+                Some((first, last, Some(replacement.to_string())))
+            }
+            MutationType::Index => {
+                let (first, last) = index_argument(file, idx)?;
+                let index = file.slice(file.span(first, last));
+                let replacement = if file.operand_end(first) == Some(last) {
+                    format!("{} {}", index, MutationType::PlusOne.as_str())
+                } else {
+                    format!("({}) {}", index, MutationType::PlusOne.as_str())
+                };
+                Some((first, last, Some(replacement)))
+            }
+            MutationType::Len => {
+                let first = file.receiver_start(idx - 1)?;
+                let len = file.slice(file.span(first, idx + 2));
+                let replacement = format!("({} {})", len, MutationType::MinusOne.as_str());
+                Some((first, idx + 2, Some(replacement)))
+            }
+            // Nothing is popped, a used result becomes `Option::None`
+            MutationType::PopFront => {
+                let first = file.receiver_start(idx - 1)?;
+                match file.removal(first, idx + 2) {
+                    Some((last, replacement)) => Some((first, last, Some(replacement.to_string()))),
+                    None => Some((first, idx + 2, Some("Option::None".to_string()))),
+                }
+            }
+            MutationType::DispatcherCall => {
+                let (last, replacement) = file.removal(idx, dispatcher_call(file, idx)?)?;
+                Some((idx, last, Some(replacement.to_string())))
+            }
+            // The dispatcher calls the contract itself, `{ contract_address }` included
+            MutationType::DispatcherAddress => {
+                let replacement = format!(
+                    "{}: starknet::{}()",
+                    self.as_str(),
+                    MutationType::ContractAddress.as_str()
+                );
+                Some((idx, dispatcher_address(file, idx)?, Some(replacement)))
+            }
+            MutationType::Expect => {
+                let replacement = format!("{}()", MutationType::UnwrapOrDefault.as_str());
+                Some((idx, file.matching(idx + 1)?, Some(replacement)))
+            }
+            MutationType::Return => {
+                let (last, replacement) = return_removal(file, idx)?;
+                Some((idx, last, Some(replacement.to_string())))
+            }
+            MutationType::Break => {
+                let (last, replacement) = break_removal(file, idx)?;
+                Some((idx, last, Some(replacement.to_string())))
+            }
+            MutationType::LoopStep => {
+                let step = step_of_one(file.text(idx + 1))?;
+                Some((idx + 1, idx + 1, Some(step)))
+            }
+            // The path is kept, `Option::Some(x)` => `Option::None`
+            MutationType::OptionSome => Some((idx, variant_call(file, idx)?, None)),
+            MutationType::ResultOk => {
+                let function = file.function_at(file.tokens[idx].span.start)?;
+                let error = error_value(function.return_type.as_deref())?;
+                let replacement = format!("{}({})", MutationType::ResultErr.as_str(), error);
+                Some((idx, variant_call(file, idx)?, Some(replacement)))
+            }
+            _ => Some((idx, idx, None)),
+        }
+    }
+
+    // Mutations at a site as first and last tokens replaced, target and replacement
+    fn replacements(
+        &self,
+        file: &SourceFile,
+        idx: usize,
+    ) -> Vec<(usize, usize, MutationType, String)> {
+        match self {
+            MutationType::FunctionBody => {
+                let Some(function) = file.function_of(idx) else {
+                    return vec![];
+                };
+                let open = function.body_open;
+                let Some(close) = file.matching(open) else {
+                    return vec![];
+                };
+                let body = file.slice(function.body);
+                let inner = body[1..body.len() - 1].trim();
+                let indent = file.indentation(function.span.start);
+                default_values(function.return_type.as_deref())
+                    .into_iter()
+                    // Skip the mutation if the body is already the default value
+                    .filter(|value| value != inner)
+                    .map(|value| {
+                        let replacement = if value.is_empty() {
+                            "{}".to_string()
+                        } else {
+                            format!("{{\n{}    {}\n{}}}", indent, value, indent)
+                        };
+                        (open, close, MutationType::DefaultValue, replacement)
+                    })
+                    .collect()
+            }
+            // Only the first default value, the rest of the body is kept after the return
+            MutationType::EarlyReturn => {
+                let Some(function) = file.function_of(idx) else {
+                    return vec![];
+                };
+                let open = function.body_open;
+                let Some(value) = default_values(function.return_type.as_deref())
+                    .into_iter()
+                    .next()
+                else {
+                    return vec![];
+                };
+                if file.is(open + 1, "}") {
                     return vec![];
                 }
-                vec![Mutation {
-                    from: self.clone(),
-                    to: MutationType::Comment,
-                    file_name,
-                    line,
-                    pos,
-                }]
+                let statement = if value.is_empty() {
+                    "return;".to_string()
+                } else {
+                    format!("return {};", value)
+                };
+                let indent = file.indentation(function.span.start);
+                let replacement = format!("{{\n{}    {}", indent, statement);
+                vec![(open, open, MutationType::DefaultValue, replacement)]
             }
-            MutationType::Comment => {
-                panic!("Comment mutation should not be used");
+            MutationType::AssertMacro
+            | MutationType::AssertEq
+            | MutationType::AssertNe
+            | MutationType::AssertLt
+            | MutationType::AssertLe
+            | MutationType::AssertGt
+            | MutationType::AssertGe => {
+                let Some(close) = file.matching(idx + 2) else {
+                    return vec![];
+                };
+                self.targets()
+                    .into_iter()
+                    .filter_map(|to| match to {
+                        MutationType::Removed => {
+                            let (last, replacement) = file.removal(idx, close)?;
+                            Some((idx, last, to, replacement.to_string()))
+                        }
+                        _ => {
+                            let replacement = to.as_str().to_string();
+                            Some((idx, idx, to, replacement))
+                        }
+                    })
+                    .collect()
+            }
+            MutationType::IfElse => {
+                let (Some((first, last)), Some((then_block, else_block))) =
+                    (file.condition(idx), file.branches(idx))
+                else {
+                    return vec![];
+                };
+                let condition = file.slice(file.span(first, last));
+                self.targets()
+                    .into_iter()
+                    .filter_map(|to| match to {
+                        MutationType::True | MutationType::False => {
+                            let replacement = to.as_str().to_string();
+                            (condition != replacement).then_some((first, last, to, replacement))
+                        }
+                        // Only when the `if` has no value, e.g. `let a = if ... else ...;`
+                        MutationType::Removed => {
+                            let (_, else_close) = else_block?;
+                            let (then_open, then_close) = then_block;
+                            let unit = then_close == then_open + 1
+                                || matches!(file.text(then_close - 1), ";" | "}");
+                            unit.then_some((then_close, else_close, to, "}".to_string()))
+                        }
+                        _ => {
+                            let (else_open, else_close) = else_block?;
+                            let (then_open, then_close) = then_block;
+                            let then_span = file.span(then_open, then_close);
+                            let else_span = file.span(else_open, else_close);
+                            let between = Span::new(then_span.end, else_span.start);
+                            let replacement = format!(
+                                "{}{}{}",
+                                file.slice(else_span),
+                                file.slice(between),
+                                file.slice(then_span)
+                            );
+                            Some((then_open, else_close, to, replacement))
+                        }
+                    })
+                    .collect()
+            }
+            MutationType::MatchArm => {
+                let Some(arms) = file.arms(idx) else {
+                    return vec![];
+                };
+                let wildcard = arms.iter().find(|arm| arm.is_wildcard(file));
+                let body = |arm: &Arm| file.slice(file.span(arm.body.0, arm.body.1));
+                // Body of `from` moved into `to`, braced when `to` has no comma to end it
+                let moved = |from: &Arm, to: &Arm| {
+                    if to.end == to.body.1 && !file.is(from.body.0, "{") {
+                        format!("{{ {} }}", body(from))
+                    } else {
+                        body(from).to_string()
+                    }
+                };
+                let mut mutations = Vec::new();
+                // Without the arm its values fall through to the wildcard
+                if wildcard.is_some() {
+                    for arm in arms.iter().filter(|arm| !arm.is_wildcard(file)) {
+                        let removed =
+                            (arm.pattern.0, arm.end, MutationType::Removed, String::new());
+                        mutations.push(removed);
+                    }
+                }
+                for pair in arms.windows(2) {
+                    let (first, second) = (&pair[0], &pair[1]);
+                    if body(first) == body(second) {
+                        continue;
+                    }
+                    let between = Span::new(
+                        file.tokens[first.body.1].span.end,
+                        file.tokens[second.body.0].span.start,
+                    );
+                    let replacement = format!(
+                        "{}{}{}",
+                        moved(second, first),
+                        file.slice(between),
+                        moved(first, second)
+                    );
+                    mutations.push((
+                        first.body.0,
+                        second.body.1,
+                        MutationType::SwappedArms,
+                        replacement,
+                    ));
+                }
+                if let Some(wildcard) = wildcard {
+                    for arm in arms.iter().filter(|arm| body(arm) != body(wildcard)) {
+                        let replacement = moved(wildcard, arm);
+                        mutations.push((
+                            arm.body.0,
+                            arm.body.1,
+                            MutationType::WildcardBody,
+                            replacement,
+                        ));
+                    }
+                }
+                mutations
+            }
+            // One more iteration, `i < len` => `i <= len` and `if i == len { break; }` =>
+            // `if i > len { break; }`, or `if len < i { break; }` with the bound on the left
+            MutationType::LoopBound => {
+                let Some(to) = loop_bound(file, idx) else {
+                    return vec![];
+                };
+                let replacement = to.as_str().to_string();
+                vec![(idx, idx, to, replacement)]
+            }
+            MutationType::TryInto => {
+                let Some(first) = file.receiver_start(idx - 1) else {
+                    return vec![];
+                };
+                let last = idx + 6;
+                let conversion = file.slice(file.span(first, last));
+                let unsigned = conversion_target(file, first, last)
+                    .is_some_and(|t| t.starts_with('u') && INTEGERS.contains(&t));
+                self.targets()
+                    .into_iter()
+                    .filter(|to| unsigned || !matches!(to, MutationType::Truncated))
+                    .map(|to| {
+                        let replacement = match to {
+                            // Low 7 bits, which changes large enough values of any unsigned type
+                            MutationType::Truncated => format!("({} {})", conversion, to.as_str()),
+                            _ => to.as_str().to_string(),
+                        };
+                        (first, last, to, replacement)
+                    })
+                    .collect()
+            }
+            // The value itself, or its opposite
+            MutationType::Abs => {
+                let Some(((first, last), (value_first, value_last))) = abs(file, idx) else {
+                    return vec![];
+                };
+                let value = file.slice(file.span(value_first, value_last)).to_string();
+                let negated = negate(file, value_first, value_last);
+                vec![
+                    (first, last, MutationType::Removed, value),
+                    (first, last, MutationType::Negated, negated),
+                ]
+            }
+            MutationType::IntegerLiteral => literal_boundaries(file.text(idx))
+                .into_iter()
+                .map(|(to, replacement)| (idx, idx, to, replacement))
+                .collect(),
+            MutationType::CallerAddress
+            | MutationType::ContractAddress
+            | MutationType::BlockTimestamp
+            | MutationType::BlockNumber => {
+                // Include the path, e.g. `starknet::get_caller_address()`
+                let first = file.path_start(idx);
+                let call = file.slice(file.span(first, idx + 2));
+                self.targets()
+                    .into_iter()
+                    .map(|to| {
+                        let replacement = match to {
+                            MutationType::ZeroValue => to.as_str().to_string(),
+                            MutationType::PlusOne => format!("({} {})", call, to.as_str()),
+                            _ => format!("starknet::{}()", to.as_str()),
+                        };
+                        (first, idx + 2, to, replacement)
+                    })
+                    .collect()
+            }
+            _ => {
+                let Some((first, last, replacement)) = self.site_span(file, idx) else {
+                    return vec![];
+                };
+                self.targets()
+                    .into_iter()
+                    .map(|to| {
+                        let replacement = replacement
+                            .clone()
+                            .unwrap_or_else(|| to.as_str().to_string());
+                        (first, last, to, replacement)
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    pub fn others(&self, file: &SourceFile) -> Vec<Mutation> {
+        let mut mutations = Vec::new();
+        for idx in self.sites(file) {
+            for (first, last, to, replacement) in self.replacements(file, idx) {
+                mutations.push(Mutation::new(
+                    file,
+                    self.clone(),
+                    to,
+                    first,
+                    last,
+                    replacement,
+                ));
+            }
+        }
+        mutations
+    }
+}
+
+// Closing parenthesis of `self.<var>[.entry(key)...].write(...)` starting at idx
+fn storage_write(file: &SourceFile, idx: usize) -> Option<usize> {
+    let close = file.operand_end(idx)?;
+    let open = file.matching(close)?;
+    (file.text(close) == ")"
+        && open > idx + 3
+        && file.is_sequence(idx, &["self", "."])
+        && file.is_sequence(open - 2, &[".", "write"]))
+    .then_some(close)
+}
+
+// Closing parenthesis of `self.<component>.assert_only_<role>(...)` or of an `assert(...)`
+// checking the caller, starting at idx
+fn access_guard(file: &SourceFile, idx: usize) -> Option<usize> {
+    match file.text(idx) {
+        "self" => {
+            let close = file.operand_end(idx)?;
+            let open = file.matching(close)?;
+            (file.text(close) == ")"
+                && file.text(open - 2) == "."
+                && file.text(open - 1).starts_with("assert_only"))
+            .then_some(close)
+        }
+        "assert" => {
+            let open = if file.is(idx + 1, "!") {
+                idx + 2
+            } else {
+                idx + 1
+            };
+            if !file.is(open, "(") {
+                return None;
+            }
+            let close = file.matching(open)?;
+            let callers = caller_bindings(file, idx);
+            (open..close)
+                .any(|i| {
+                    file.text(i) == "get_caller_address"
+                        || (file.kind(i) == TokenKind::Ident && callers.contains(&file.text(i)))
+                })
+                .then_some(close)
+        }
+        _ => None,
+    }
+}
+
+// Names bound before idx in its function by `let caller = get_caller_address();`
+fn caller_bindings(file: &SourceFile, idx: usize) -> Vec<&str> {
+    let Some(function) = file.function_at(file.tokens[idx].span.start) else {
+        return vec![];
+    };
+    let first = file
+        .tokens
+        .partition_point(|t| t.span.start < function.span.start);
+    (first..idx)
+        .filter(|&k| file.text(k) == "let" && file.kind(k + 1) == TokenKind::Ident)
+        .filter_map(|k| {
+            let name = if file.is(k + 1, "mut") { k + 2 } else { k + 1 };
+            let mut value = name + 2;
+            while file.is(value + 1, "::") {
+                value += 2;
+            }
+            (file.is(name + 1, "=")
+                && file.is_sequence(value, &["get_caller_address", "(", ")", ";"]))
+            .then(|| file.text(name))
+        })
+        .collect()
+}
+
+// Closing parenthesis of `self.emit(...)` starting at idx
+fn emit(file: &SourceFile, idx: usize) -> Option<usize> {
+    if !file.is_sequence(idx, &["self", ".", "emit", "("]) {
+        return None;
+    }
+    let close = file.matching(idx + 3)?;
+    (file.operand_end(idx) == Some(close)).then_some(close)
+}
+
+// Closing parenthesis of a call through a dispatcher at idx whose result is unused, e.g.
+// `IERC20Dispatcher { contract_address }.transfer(...);` or the same call on a dispatcher held
+// by a local, a parameter or a storage variable like `self.token.read().transfer(...);`
+fn dispatcher_call(file: &SourceFile, idx: usize) -> Option<usize> {
+    if !file.is_statement_start(idx) {
+        return None;
+    }
+    let receiver_end = if file.text(idx).ends_with("Dispatcher") && file.is(idx + 1, "{") {
+        file.matching(idx + 1)?
+    } else if file.is_sequence(idx, &["self", "."])
+        && file.is_sequence(idx + 3, &[".", "read", "(", ")"])
+    {
+        let open = (0..idx).find(|&k| file.is_sequence(k, &["struct", "Storage", "{"]))? + 2;
+        let storage = open..file.matching(open)?;
+        is_dispatcher(file, storage, file.text(idx + 2)).then_some(idx + 6)?
+    } else {
+        let function = file.function_at(file.tokens[idx].span.start)?;
+        let first = file
+            .tokens
+            .partition_point(|t| t.span.start < function.span.start);
+        is_dispatcher(file, first..idx, file.text(idx)).then_some(idx)?
+    };
+    if !file.is(receiver_end + 1, ".") || !file.is(receiver_end + 3, "(") {
+        return None;
+    }
+    let close = file.matching(receiver_end + 3)?;
+    file.is(close + 1, ";").then_some(close)
+}
+
+// `name` is declared in the token range as a dispatcher, `name: IERC20Dispatcher` or
+// `let name = IERC20Dispatcher { ... }`
+fn is_dispatcher(file: &SourceFile, range: std::ops::Range<usize>, name: &str) -> bool {
+    let type_at = |mut k: usize| {
+        while file.is(k + 1, "::") {
+            k += 2;
+        }
+        file.text(k).ends_with("Dispatcher")
+    };
+    range.into_iter().any(|k| {
+        file.text(k) == name
+            && file.kind(k) == TokenKind::Ident
+            && match file.text(k + 1) {
+                ":" => type_at(k + 2),
+                "=" => k > 0 && matches!(file.text(k - 1), "let" | "mut") && type_at(k + 2),
+                _ => false,
+            }
+    })
+}
+
+// Last token of a signed value starting at idx, the value of a `let x: i128 = ...;` or a literal
+// typed as a signed integer like `5_i64`
+fn signed_value(file: &SourceFile, idx: usize) -> Option<usize> {
+    let signed = |text: &str| INTEGERS.iter().any(|i| i.starts_with('i') && text == *i);
+    let negative =
+        file.text(idx) == "-" || (idx > 0 && file.text(idx - 1) == "-" && !file.is_binary(idx - 1));
+    if negative {
+        return None;
+    }
+    if idx >= 3
+        && file.text(idx - 1) == "="
+        && file.text(idx - 3) == ":"
+        && signed(file.text(idx - 2))
+    {
+        return Some(file.statement_end(idx)? - 1);
+    }
+    let literal = file.text(idx);
+    let typed = file.kind(idx) == TokenKind::Number
+        && literal
+            .rsplit_once('_')
+            .is_some_and(|(_, suffix)| signed(suffix));
+    typed.then_some(idx)
+}
+
+// Opposite of the expression `first..=last`
+fn negate(file: &SourceFile, first: usize, last: usize) -> String {
+    let value = file.slice(file.span(first, last));
+    if first == last || file.operand_end(first) == Some(last) {
+        format!("-{}", value)
+    } else {
+        format!("-({})", value)
+    }
+}
+
+// First and last tokens of an abs-like expression at idx and of its value, `x.abs()` or
+// `if x < 0 { -x } else { x }` in any order
+fn abs(file: &SourceFile, idx: usize) -> Option<((usize, usize), (usize, usize))> {
+    if file.text(idx) == "abs" {
+        let first = file.receiver_start(idx.checked_sub(1)?)?;
+        return file
+            .is_sequence(idx + 1, &["(", ")"])
+            .then_some(((first, idx + 2), (first, idx - 2)));
+    }
+    let ((then_open, then_close), Some((else_open, else_close))) = file.branches(idx)? else {
+        return None;
+    };
+    let (then_first, then_last) = (then_open + 1, then_close - 1);
+    let (else_first, else_last) = (else_open + 1, else_close - 1);
+    if then_first > then_last || else_first > else_last {
+        return None;
+    }
+    let then_value = file.slice(file.span(then_first, then_last));
+    let else_value = file.slice(file.span(else_first, else_last));
+    let value = if then_value.strip_prefix('-').map(str::trim) == Some(else_value) {
+        (else_first, else_last)
+    } else if else_value.strip_prefix('-').map(str::trim) == Some(then_value) {
+        (then_first, then_last)
+    } else {
+        return None;
+    };
+    Some(((idx, else_close), value))
+}
+
+// Receiver and closing parenthesis of an `arr.append(...)` at idx
+fn append(file: &SourceFile, idx: usize) -> Option<(usize, usize)> {
+    if idx == 0 || !file.is(idx + 1, "(") {
+        return None;
+    }
+    let first = file.receiver_start(idx - 1)?;
+    let close = file.matching(idx + 1)?;
+    (file.is_statement_start(first) && file.is(close + 1, ";")).then_some((first, close))
+}
+
+// First and last tokens of the index of a `span.at(i)` or an `arr[i]` at idx
+fn index_argument(file: &SourceFile, idx: usize) -> Option<(usize, usize)> {
+    let open = match file.text(idx) {
+        "at" if idx > 0 && file.text(idx - 1) == "." => idx + 1,
+        "[" if file.is_binary(idx) => idx,
+        _ => return None,
+    };
+    if !file.is(open, "(") && !file.is(open, "[") {
+        return None;
+    }
+    let close = file.matching(open)?;
+    let single = close > open + 1 && !(open + 1..close).any(|i| file.text(i) == ",");
+    single.then_some((open + 1, close - 1))
+}
+
+// Last token of the `contract_address` field at idx of a dispatcher
+fn dispatcher_address(file: &SourceFile, idx: usize) -> Option<usize> {
+    if idx < 2 || !file.is(idx - 1, "{") || !file.text(idx - 2).ends_with("Dispatcher") {
+        return None;
+    }
+    let last = file.matching(idx - 1)? - 1;
+    let value = file.slice(file.span(idx, last));
+    (!value.contains(MutationType::ContractAddress.as_str())).then_some(last)
+}
+
+// Short string at idx is the message of an `assert(...)`, a `panic_with_felt252(...)` or an
+// `.expect(...)`
+fn is_error_message(file: &SourceFile, idx: usize) -> bool {
+    let mut open = idx;
+    while open > 0 {
+        open -= 1;
+        match file.text(open) {
+            ")" | "]" | "}" => match file.matching(open) {
+                Some(i) => open = i,
+                None => return false,
+            },
+            "(" => break,
+            "[" | "{" | ";" => return false,
+            _ => {}
+        }
+    }
+    if open == 0 || file.text(open) != "(" {
+        return false;
+    }
+    match file.text(open - 1) {
+        "assert" => file.text(idx - 1) == ",",
+        "panic_with_felt252" | "expect" => idx == open + 1,
+        _ => false,
+    }
+}
+
+// Short string without its last character, keeping the type suffix
+fn chop(literal: &str) -> Option<String> {
+    let close = literal.rfind('\'')?;
+    let message = &literal[1..close];
+    if message.is_empty() || message.contains('\\') {
+        return None;
+    }
+    let mut chars = message.chars();
+    chars.next_back();
+    Some(format!("'{}{}", chars.as_str(), &literal[close..]))
+}
+
+// Last token and replacement to remove a `return` statement at idx which doesn't give the value
+// of its function, as its last statement or the last one of a branch ending the function
+fn return_removal(file: &SourceFile, idx: usize) -> Option<(usize, &'static str)> {
+    if !file.is_statement_start(idx) {
+        return None;
+    }
+    let end = file.statement_end(idx)?;
+    let function = file.function_at(file.tokens[idx].span.start)?;
+    if is_tail(file, function.body_open, end) {
+        return None;
+    }
+    file.removal(idx, end - 1)
+}
+
+// Statement or expression ending at `last` is the tail of the function body opened at
+// `body_open`, directly or through the branches of an `if` or a `match`
+fn is_tail(file: &SourceFile, body_open: usize, last: usize) -> bool {
+    let close = last + 1;
+    let Some(open) = file.matching(close).filter(|_| file.is(close, "}")) else {
+        return false;
+    };
+    if open == body_open {
+        return true;
+    }
+    let mut end = close;
+    while file.is(end + 1, "else") {
+        let branch = if file.is(end + 2, "if") {
+            file.condition(end + 2).map(|(_, last)| last + 1)
+        } else {
+            Some(end + 2)
+        };
+        match branch.and_then(|b| file.matching(b)) {
+            Some(branch_close) => end = branch_close,
+            None => return false,
+        }
+    }
+    if open > 0 && file.is(open - 1, "=>") {
+        let match_open = (0..open)
+            .rev()
+            .find(|&k| file.is(k, "{") && file.matching(k).is_some_and(|c| c > close));
+        match match_open.and_then(|k| file.matching(k)) {
+            Some(match_close) => end = match_close,
+            None => return false,
+        }
+    }
+    is_tail(file, body_open, end)
+}
+
+// Last token and replacement to remove the bare `break` at idx, a `break value;` can't be
+// removed from a loop returning a value
+fn break_removal(file: &SourceFile, idx: usize) -> Option<(usize, &'static str)> {
+    if file.is_statement_start(idx) && !file.is(idx + 1, ";") {
+        return None;
+    }
+    file.removal(idx, idx)
+}
+
+// `1` as a step of two, keeping the type suffix
+fn step_of_one(literal: &str) -> Option<String> {
+    literal
+        .strip_prefix('1')
+        .filter(|suffix| suffix.is_empty() || suffix.starts_with('_'))
+        .map(|suffix| format!("2{}", suffix))
+}
+
+// Comparison at idx ending a loop, the condition of a `while` or of an `if` breaking out when
+// the loop counter reaches a bound, with the comparison running the loop once more
+fn loop_bound(file: &SourceFile, idx: usize) -> Option<MutationType> {
+    let start = (0..idx)
+        .rev()
+        .take_while(|&k| !matches!(file.text(k), "{" | "}" | ";"))
+        .find(|&k| matches!(file.text(k), "while" | "if"))?;
+    let (first, last) = file.condition(start)?;
+    if idx < first || last < idx {
+        return None;
+    }
+    match (file.text(start), file.text(idx)) {
+        ("while", "<") => Some(MutationType::LessThanOrEqual),
+        ("if", "==") if file.is(last + 2, "break") => {
+            let (left, right) = ((first, idx - 1), (idx + 1, last));
+            // The counter is on the left unless the bound is
+            if is_counter(file, left) || (!is_counter(file, right) && is_len(file, right)) {
+                Some(MutationType::GreaterThan)
+            } else if is_counter(file, right) || is_len(file, left) {
+                Some(MutationType::LessThan)
+            } else {
+                None
             }
+        }
+        _ => None,
+    }
+}
+
+// Expression `first..=last` is a `.len()` call
+fn is_len(file: &SourceFile, (first, last): (usize, usize)) -> bool {
+    last >= first + 3 && file.is_sequence(last - 3, &[".", "len", "(", ")"])
+}
+
+// Expression `first..=last` is the counter of the loop containing it
+fn is_counter(file: &SourceFile, (first, last): (usize, usize)) -> bool {
+    let Some((open, close)) = file.enclosing_loop(first) else {
+        return false;
+    };
+    let counter = file.text(first);
+    first == last
+        && file.kind(first) == TokenKind::Ident
+        && (open..close).any(|j| file.text(j) == counter && matches!(file.text(j + 1), "+=" | "-="))
+}
+
+// Closing parenthesis of a `Some(...)` or `Ok(...)` built at idx, patterns are left alone
+fn variant_call(file: &SourceFile, idx: usize) -> Option<usize> {
+    if !file.is(idx + 1, "(") {
+        return None;
+    }
+    let close = file.matching(idx + 1)?;
+    let first = file.path_start(idx);
+    let in_let = first > 0 && file.text(first - 1) == "let";
+    let in_arm = file.is(close + 1, "=>") || file.is(close + 1, "|");
+    (!in_let && !in_arm).then_some(close)
+}
 
-            MutationType::IsZero => vec![Mutation {
-                from: self.clone(),
-                to: MutationType::IsNonZero,
-                file_name,
-                line,
-                pos,
-            }],
-            MutationType::IsNonZero => vec![Mutation {
-                from: self.clone(),
-                to: MutationType::IsZero,
-                file_name,
-                line,
-                pos,
-            }],
-            MutationType::And => vec![Mutation {
-                from: self.clone(),
-                to: MutationType::Or,
-                file_name,
-                line,
-                pos,
-            }],
-            MutationType::Or => vec![Mutation {
-                from: self.clone(),
-                to: MutationType::And,
-                file_name,
-                line,
-                pos,
-            }],
+// Error built in place of an `Ok(...)` of a function returning `Result<T, E>`
+fn error_value(return_type: Option<&str>) -> Option<String> {
+    let return_type = return_type?.trim();
+    let args = return_type.strip_suffix('>')?.split_once('<')?;
+    if args.0.rsplit("::").next() != Some("Result") {
+        return None;
+    }
+    // Split `T, E` on the top level comma
+    let mut depth = 0;
+    let comma = args.1.char_indices().find(|&(_, c)| {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            _ => {}
         }
+        c == ',' && depth == 0
+    })?;
+    let error = &args.1[comma.0 + 1..];
+    let value = default_values(Some(error)).into_iter().next()?;
+    Some(if value.is_empty() {
+        "()".to_string()
+    } else {
+        value
+    })
+}
+
+const INTEGERS: [&str; 13] = [
+    "felt252", "u8", "u16", "u32", "u64", "u128", "u256", "usize", "i8", "i16", "i32", "i64",
+    "i128",
+];
+
+// Largest value of an integer type, as hexadecimal digits
+fn type_max(integer: &str) -> Option<String> {
+    let (signed, bits) = match integer {
+        "usize" => (false, 32),
+        _ => (
+            integer.starts_with('i'),
+            integer.get(1..)?.parse::<usize>().ok()?,
+        ),
+    };
+    let digits = "f".repeat(bits / 4);
+    Some(if signed {
+        format!("7{}", &digits[1..])
+    } else {
+        digits
+    })
+}
+
+// Type of the conversion `first..=last`, annotated on its `let` or returned by its function
+fn conversion_target(file: &SourceFile, first: usize, last: usize) -> Option<&str> {
+    if first >= 3 && file.is(first - 1, "=") && file.is(first - 3, ":") {
+        return Some(file.text(first - 2));
+    }
+    let function = file.function_at(file.tokens[first].span.start)?;
+    if is_tail(file, function.body_open, last) {
+        return function.return_type.as_deref();
     }
+    None
+}
+
+// Boundary neighbours of an integer literal, keeping its base and type suffix
+fn literal_boundaries(literal: &str) -> Vec<(MutationType, String)> {
+    let integer = INTEGERS
+        .iter()
+        .find(|i| *i != &"felt252" && literal.ends_with(*i) && literal.len() > i.len())
+        .filter(|i| literal.ends_with(&format!("_{}", i)) || !literal.starts_with("0x"));
+    let number = integer
+        .map_or(literal, |i| &literal[..literal.len() - i.len()])
+        .replace('_', "");
+    let (radix, digits) = match number.get(..2) {
+        Some("0x") => (16, &number[2..]),
+        Some("0o") => (8, &number[2..]),
+        Some("0b") => (2, &number[2..]),
+        _ => (10, &number[..]),
+    };
+    let Ok(value) = u128::from_str_radix(digits, radix) else {
+        return vec![];
+    };
+    let suffix = integer.map_or(String::new(), |i| format!("_{}", i));
+    let format = |value: u128| -> String {
+        match radix {
+            16 => format!("0x{:x}{}", value, suffix),
+            8 => format!("0o{:o}{}", value, suffix),
+            2 => format!("0b{:b}{}", value, suffix),
+            _ => format!("{}{}", value, suffix),
+        }
+    };
+
+    // Past the max of its type the literal doesn't build
+    let max = integer.and_then(|i| type_max(i));
+    let at_max = max.as_ref() == Some(&format!("{:x}", value));
+    let mut boundaries = Vec::new();
+    if let Some(next) = value.checked_add(1).filter(|_| !at_max) {
+        boundaries.push((MutationType::PlusOne, format(next)));
+    }
+    if value > 0 {
+        boundaries.push((MutationType::MinusOne, format(value - 1)));
+    }
+    if let Some(max) = max.filter(|_| !at_max) {
+        boundaries.push((MutationType::TypeMax, format!("0x{}{}", max, suffix)));
+    }
+    boundaries
+}
+
+// Values a function returning `return_type` can be reduced to, an empty value empties the body
+fn default_values(return_type: Option<&str>) -> Vec<String> {
+    let Some(return_type) = return_type.map(str::trim) else {
+        return vec![String::new()];
+    };
+    // `core::option::Option<T>` => `Option`
+    let name = return_type
+        .split('<')
+        .next()
+        .unwrap_or_default()
+        .rsplit("::")
+        .next()
+        .unwrap_or_default();
+    let values = match name {
+        "()" => vec![""],
+        "bool" => vec!["true", "false"],
+        _ if INTEGERS.contains(&name) => vec!["0", "1"],
+        "Array" => vec!["array![]"],
+        "Span" => vec!["array![].span()"],
+        "Option" => vec!["Option::None"],
+        "ByteArray" => vec!["\"\""],
+        // The default value of the error
+        "Result" => {
+            return error_value(Some(return_type))
+                .map(|error| format!("Result::{}({})", MutationType::ResultErr.as_str(), error))
+                .into_iter()
+                .collect();
+        }
+        _ => vec!["Default::default()"],
+    };
+    values.into_iter().map(String::from).collect()
 }
 
 impl Mutation {
+    fn new(
+        file: &SourceFile,
+        from: MutationType,
+        to: MutationType,
+        first: usize,
+        last: usize,
+        replacement: String,
+    ) -> Self {
+        let span = file.span(first, last);
+        let lines = file.lines_span(span);
+        Mutation {
+            from,
+            to,
+            file_name: file.file_name.clone(),
+            span,
+            replacement,
+            function: file.function_at(span.start).map(|f| f.name.clone()),
+            line: file.slice(lines).to_string(),
+            line_start: lines.start,
+            pos: file.line_of(span.start),
+            col: file.source[lines.start..span.start].chars().count(),
+        }
+    }
+
+    pub fn category(&self) -> Option<&'static str> {
+        self.from.category()
+    }
+
+    pub fn is_high_severity(&self) -> bool {
+        self.from.is_high_severity()
+    }
+
+    // Identifies the code produced by the mutation
+    pub fn key(&self) -> (PathBuf, Span, String) {
+        (self.file_name.clone(), self.span, self.replacement.clone())
+    }
+
+    fn mutated_line(&self) -> String {
+        let start = self.span.start - self.line_start;
+        let end = self.span.end - self.line_start;
+        format!(
+            "{}{}{}",
+            &self.line[..start],
+            self.replacement,
+            &self.line[end..]
+        )
+    }
+
     pub fn apply_mutation(&self, path_src: &Path, path_dst: &Path) {
         copy_cairo_project(path_src, path_dst).expect("Couldn't copy test data");
 
-        // Mutation from as fn
-        let new_line = self.line.replace(self.from.as_str(), self.to.as_str());
-
         let file_dst = path_dst.join(self.file_name.clone());
-        change_line_content(&file_dst, self.pos + 1, &new_line).expect("Error applying mutation");
+        change_file_content(&file_dst, self.span, &self.replacement)
+            .expect("Error applying mutation");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MutationType;
+    use crate::syntax::SourceFile;
+    use std::path::PathBuf;
+
+    fn parse(source: &str) -> SourceFile {
+        SourceFile::new(PathBuf::from("src/lib.cairo"), source.to_string())
+    }
+
+    fn mutated(file: &SourceFile, mutation_type: MutationType) -> Vec<String> {
+        mutation_type
+            .others(file)
+            .iter()
+            .map(|m| m.mutated_line())
+            .collect()
+    }
+
+    #[test]
+    fn test_one_mutation_per_occurrence() {
+        let source = "fn simple(a: u8, b: u8) -> bool {\n    a == b && b == 'a == b'\n}";
+        let file = parse(source);
+        let mutations = MutationType::Equal.others(&file);
+        assert_eq!(mutations.len(), 2);
+        assert_eq!(mutations[0].col, 6);
+        assert_eq!(mutations[0].mutated_line(), "    a != b && b == 'a == b'");
+        assert_eq!(mutations[1].col, 16);
+        assert_eq!(mutations[1].mutated_line(), "    a == b && b != 'a == b'");
+    }
+
+    #[test]
+    fn test_multiline_assert() {
+        let source = "fn simple(a: u8) {\n    assert(\n        a == 1,\n        'fail'\n    );\n}";
+        let file = parse(source);
+        let mutations = MutationType::Assert.others(&file);
+        assert_eq!(mutations.len(), 1);
+        assert_eq!(mutations[0].pos, 1);
+        assert_eq!(mutations[0].line.lines().count(), 4);
+        assert_eq!(mutations[0].mutated_line(), "    ");
+    }
+
+    #[test]
+    fn test_bitwise() {
+        let source = "fn simple(a: u8, b: u8) -> u8 {\n    a << 1 ^ b & 3 | a >> 1\n}";
+        let file = parse(source);
+        assert_eq!(
+            mutated(&file, MutationType::ShiftLeft),
+            vec!["    a >> 1 ^ b & 3 | a >> 1"]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::ShiftRight),
+            vec!["    a << 1 ^ b & 3 | a << 1"]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::BitXor),
+            vec!["    a << 1  | a >> 1"]
+        );
+        assert!(mutated(&file, MutationType::LessThan).is_empty());
+        assert!(mutated(&file, MutationType::GreaterThan).is_empty());
+        // `|` between arm patterns or around closure parameters isn't an operator
+        let source = "fn f(e: E, a: u8) -> u8 {\n    match e {\n        E::A | E::B => a | 1,\n        _ => 0,\n    }\n}\nfn g(a: Array<u8>) {\n    let f = |x: u8| x | 1;\n    a.map(|x| x + 1);\n}";
+        let file = parse(source);
+        assert_eq!(
+            mutated(&file, MutationType::BitOr),
+            vec![
+                "        E::A | E::B => a & 1,",
+                "    let f = |x: u8| x & 1;"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_boolean() {
+        let source = "fn simple(self: @S, a: u8) {\n    assert!(!a.is_zero(), 'zero');\n    if self.paused.read() {\n        assert(a < 10, 'too big');\n    }\n    if !is_ok(a) {\n        assert(!a.is_zero() && a > 1, 'small');\n    }\n    assert!(a != 5, \"five\");\n}";
+        let file = parse(source);
+        assert_eq!(
+            mutated(&file, MutationType::Not),
+            vec![
+                "    assert!(a.is_zero(), 'zero');",
+                "    if is_ok(a) {",
+                "        assert(a.is_zero() && a > 1, 'small');"
+            ]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::Condition),
+            vec![
+                "    if !self.paused.read() {",
+                "        assert(!(a < 10), 'too big');",
+                "        assert(!(!a.is_zero() && a > 1), 'small');",
+                "    assert!(!(a != 5), \"five\");"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_function_body() {
+        let source = "mod a {\n    fn unit(ref self: S) {\n        self.x.write(1);\n    }\n\n    fn big(a: u8) -> bool {\n        a > 100\n    }\n\n    fn none() -> Option<u8> {\n        Option::None\n    }\n\n    fn ok() -> Result<u8, felt252> {\n        Result::Ok(1)\n    }\n}";
+        let file = parse(source);
+        let mutations = MutationType::FunctionBody.others(&file);
+        assert_eq!(mutations.len(), 4);
+        assert_eq!(mutations[0].mutated_line(), "    fn unit(ref self: S) {}");
+        assert_eq!(
+            mutations[1].mutated_line(),
+            "    fn big(a: u8) -> bool {\n        true\n    }"
+        );
+        assert_eq!(mutations[2].replacement, "{\n        false\n    }");
+        assert_eq!(mutations[3].replacement, "{\n        Result::Err(0)\n    }");
+    }
+
+    #[test]
+    fn test_storage_write() {
+        let source = "fn write(ref self: S) {\n    self.x.write(1);\n}\n\n#[starknet::contract]\nmod C {\n    fn f(ref self: ContractState, a: u8) {\n        self.x.write(self.x.read() + 1);\n        self\n            .balances\n            .entry(a)\n            .write(2);\n        self.x.read();\n    }\n    fn set(ref self: ContractState, v: u128) { self.x.write(v) }\n}";
+        let file = parse(source);
+        let mutations = MutationType::StorageWrite.others(&file);
+        assert_eq!(mutations.len(), 3);
+        assert_eq!(mutations[0].mutated_line(), "        ");
+        assert_eq!(mutations[1].line.lines().count(), 4);
+        assert_eq!(mutations[1].category(), Some("Unobserved state changes"));
+        assert_eq!(
+            mutations[2].mutated_line(),
+            "    fn set(ref self: ContractState, v: u128) { () }"
+        );
+    }
+
+    #[test]
+    fn test_emit() {
+        let source = "fn f(ref self: ContractState, amount: u128) {\n    self.emit(Increased { amount });\n    self\n        .emit(\n            Event::Increased(Increased { amount })\n        );\n    self.emit(Increased { amount }).into()\n}";
+        let file = parse(source);
+        let mutations = MutationType::Emit.others(&file);
+        assert_eq!(mutations.len(), 2);
+        assert_eq!(mutations[0].mutated_line(), "    ");
+        assert_eq!(mutations[1].line.lines().count(), 4);
+        assert_eq!(mutations[1].category(), Some("Events not asserted"));
+    }
+
+    #[test]
+    fn test_access_control() {
+        let source = "fn f(ref self: ContractState) {\n    self.ownable.assert_only_owner();\n    self.accesscontrol.assert_only_role(MINTER_ROLE);\n    assert!(get_caller_address() == self.owner.read(), \"Not owner\");\n    assert(get_block_timestamp() > 0, 'Too early');\n    let caller = starknet::get_caller_address();\n    let amount = 1;\n    assert(caller == self.admin.read(), 'Not admin');\n    assert(amount > 0, 'Zero');\n}";
+        let file = parse(source);
+        let mutations = MutationType::AccessControl.others(&file);
+        assert_eq!(mutations.len(), 4);
+        assert!(mutations.iter().all(|m| m.mutated_line().trim().is_empty()));
+        assert!(mutations[0].to_string().contains("[HIGH]"));
+    }
+
+    #[test]
+    fn test_context_calls() {
+        let source = "fn f() -> bool {\n    get_caller_address() == starknet::get_contract_address()\n        && get_block_timestamp() > 10\n}";
+        let file = parse(source);
+        assert_eq!(
+            mutated(&file, MutationType::CallerAddress),
+            vec!["    starknet::get_contract_address() == starknet::get_contract_address()"]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::ContractAddress),
+            vec!["    get_caller_address() == starknet::get_caller_address()"]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::BlockTimestamp),
+            vec![
+                "        && 0 > 10",
+                "        && (get_block_timestamp() + 1) > 10"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_integer_literal() {
+        let source = "const FEE: u16 = 0x1f_u16;\nconst TEST_AMOUNT: u128 = 100;\n\nfn f(a: (u8, u8)) -> u8 {\n    assert(a.0 > 0, 'zero');\n    a.1 * 255_u8 + 0xff_u8 + 'a1'\n}\n\nfn h(a: u8) -> u8 {\n    match a {\n        0 | 1 => 7,\n        _ => 0,\n    }\n}\n\n#[cfg(test)]\nmod tests {\n    fn g() -> u128 {\n        super::TEST_AMOUNT\n    }\n}";
+        let file = parse(source);
+        let mutations = MutationType::IntegerLiteral.others(&file);
+        let replacements: Vec<&str> = mutations.iter().map(|m| m.replacement.as_str()).collect();
+        assert_eq!(
+            replacements,
+            vec![
+                "0x20_u16",
+                "0x1e_u16",
+                "0xffff_u16",
+                "1",
+                "254_u8",
+                "0xfe_u8",
+                "8",
+                "6",
+                "1"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_assert_macros() {
+        let source = "fn f(a: u8, b: u8) {\n    assert!(a < 10, \"too big\");\n    assert_eq!(a, b);\n    let c = assert_lt!(a, b);\n}";
+        let file = parse(source);
+        assert_eq!(mutated(&file, MutationType::AssertMacro), vec!["    "]);
+        assert_eq!(
+            mutated(&file, MutationType::AssertEq),
+            vec!["    assert_ne!(a, b);", "    "]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::AssertLt),
+            vec!["    let c = assert_le!(a, b);"]
+        );
+    }
+
+    #[test]
+    fn test_option_result() {
+        let source = "fn f(a: Option<u8>) -> Result<u8, felt252> {
+    match a {
+        Option::Some(x) => Result::Ok(x),
+        Option::None => Ok(a.unwrap()),
+    }
+}
+fn g(a: u8) -> Option<u8> {
+    if let Some(x) = Some(a) {
+        return Some(x);
+    }
+    Option::None
+}";
+        let file = parse(source);
+        assert_eq!(
+            mutated(&file, MutationType::OptionSome),
+            vec!["    if let Some(x) = None {", "        return None;"]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::ResultOk),
+            vec![
+                "        Option::Some(x) => Result::Err(0),",
+                "        Option::None => Err(0),"
+            ]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::Unwrap),
+            vec!["        Option::None => Ok(a.unwrap_or_default()),"]
+        );
+    }
+
+    #[test]
+    fn test_if_else() {
+        let source = "fn f(a: u8) -> u8 {\n    let mut b = 0;\n    if a == 1 { b = 1; } else { b = 2; }\n    if is_one(a) {\n        b\n    } else if a == 2 {\n        2\n    } else {\n        3\n    }\n}";
+        let file = parse(source);
+        assert_eq!(
+            mutated(&file, MutationType::IfElse),
+            vec![
+                "    if true { b = 1; } else { b = 2; }",
+                "    if false { b = 1; } else { b = 2; }",
+                "    if a == 1 { b = 1; }",
+                "    if a == 1 { b = 2; } else { b = 1; }",
+                "    if true {",
+                "    if false {",
+                "    } else if true {",
+                "    } else if false {",
+                "    } else if a == 2 {\n        3\n    } else {\n        2\n    }",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_match_arms() {
+        let source = "fn f(a: E) -> u8 {\n    match a {\n        E::A => 1,\n        E::B => 2,\n        _ => 3,\n    }\n}";
+        let file = parse(source);
+        assert_eq!(
+            mutated(&file, MutationType::MatchArm),
+            vec![
+                "        ",
+                "        ",
+                "        E::A => 2,\n        E::B => 1,",
+                "        E::B => 3,\n        _ => 2,",
+                "        E::A => 3,",
+                "        E::B => 3,",
+            ]
+        );
+        // No wildcard, only the swap
+        let file = parse("fn f(a: bool) -> u8 {\n    match a {\n        true => 1,\n        false => 2,\n    }\n}");
+        assert_eq!(mutated(&file, MutationType::MatchArm).len(), 1);
+        // Bodies moved next to a braced arm are braced
+        let source = "fn f(a: E) -> u8 {\n    match a {\n        E::A => {\n            1\n        }\n        E::B => 2,\n        _ => 3,\n    }\n}";
+        let file = parse(source);
+        assert_eq!(
+            mutated(&file, MutationType::MatchArm),
+            vec![
+                "        ",
+                "        ",
+                "        E::A => { 2 }\n        E::B => {\n            1\n        },",
+                "        E::B => 3,\n        _ => 2,",
+                "        E::A => { 3 }",
+                "        E::B => 3,",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_returns() {
+        let source = "fn f(a: u8) -> u8 {\n    if a > 10 {\n        return 10;\n    }\n    return a;\n}\nfn g(ref a: u8) {\n    a = 1;\n}\nfn h() {}\nfn k(a: u8) -> u8 {\n    if a > 1 {\n        return 1;\n    } else if a > 0 {\n        return 2;\n    } else {\n        match a {\n            0 => { return 3; },\n            _ => { return 4; },\n        }\n    }\n}";
+        let file = parse(source);
+        assert_eq!(
+            mutated(&file, MutationType::EarlyReturn),
+            vec![
+                "fn f(a: u8) -> u8 {\n    return 0;",
+                "fn g(ref a: u8) {\n    return;",
+                "fn k(a: u8) -> u8 {\n    return 0;"
+            ]
+        );
+        assert_eq!(mutated(&file, MutationType::Return), vec!["        "]);
+    }
+
+    #[test]
+    fn test_error_propagation() {
+        let source = "fn f(a: u16) -> Option<u8> {\n    let b: u8 = a.try_into()?;\n    let c: u8 = a.try_into().expect('too big');\n    Option::Some(b + c)\n}";
+        let file = parse(source);
+        assert_eq!(
+            mutated(&file, MutationType::ErrorPropagation),
+            vec!["    let b: u8 = a.try_into().unwrap_or_default();"]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::Expect),
+            vec!["    let c: u8 = a.try_into().unwrap_or_default();"]
+        );
+    }
+
+    #[test]
+    fn test_error_message() {
+        let source = "fn f(a: u8) {\n    assert(a != 'a', 'Not owner');\n    core::panic_with_felt252('Zero'_felt252);\n    let b: u8 = a.try_into().expect('x');\n    let c = array!['abc'];\n}";
+        let file = parse(source);
+        assert_eq!(
+            mutated(&file, MutationType::ErrorMessage),
+            vec![
+                "    assert(a != 'a', 'Not owne');",
+                "    core::panic_with_felt252('Zer'_felt252);",
+                "    let b: u8 = a.try_into().expect('');",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dispatchers() {
+        let source = "struct Storage {\n    vault: IVaultDispatcher,\n    token: ContractAddress,\n}\nfn f(ref self: ContractState, token: ContractAddress, pool: IPoolDispatcher) {\n    IERC20Dispatcher { contract_address: token }.transfer(to, 1);\n    let balance = IERC20Dispatcher { contract_address }.balance_of(to);\n    let erc20 = IERC20Dispatcher { contract_address: token };\n    erc20.approve(to, balance);\n    pool.swap(1);\n    self.vault.read().deposit(1);\n    self.token.read().transfer(to, 1);\n    token_dispatcher.approve(to, balance);\n}";
+        let file = parse(source);
+        assert_eq!(
+            mutated(&file, MutationType::DispatcherCall),
+            vec!["    ", "    ", "    ", "    "]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::DispatcherAddress),
+            vec![
+                "    IERC20Dispatcher { contract_address: starknet::get_contract_address() }.transfer(to, 1);",
+                "    let balance = IERC20Dispatcher { contract_address: starknet::get_contract_address() }.balance_of(to);",
+                "    let erc20 = IERC20Dispatcher { contract_address: starknet::get_contract_address() };",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        let source = "fn f(a: felt252, b: u256) -> u128 {\n    let c: u8 = a.try_into().unwrap();\n    let d: u128 = a.try_into().expect('x');\n    let e: felt252 = b.try_into().unwrap();\n    b.low + b.high + c.into()\n}\nfn g(a: u256) -> i8 {\n    a.try_into().unwrap()\n}";
+        let file = parse(source);
+        assert_eq!(
+            mutated(&file, MutationType::TryInto),
+            vec![
+                "    let c: u8 = 0;",
+                "    let c: u8 = (a.try_into().unwrap() & 0x7f);",
+                "    let e: felt252 = 0;",
+                "    0",
+            ]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::Low),
+            vec!["    b.high + b.high + c.into()"]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::High),
+            vec!["    b.low + b.low + c.into()"]
+        );
+    }
+
+    #[test]
+    fn test_arrays() {
+        let source = "fn f(ref a: Array<u8>, mut s: Span<u8>) -> u8 {\n    a.append(*s.at(0));\n    s.pop_front();\n    let b = a[a.len() - 1];\n    *s.pop_front().unwrap() + b\n}";
+        let file = parse(source);
+        assert_eq!(mutated(&file, MutationType::Append), vec!["    "]);
+        assert_eq!(
+            mutated(&file, MutationType::Index),
+            vec![
+                "    a.append(*s.at(0 + 1));",
+                "    let b = a[(a.len() - 1) + 1];"
+            ]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::Len),
+            vec!["    let b = a[(a.len() - 1) - 1];"]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::PopFront),
+            vec!["    ", "    *Option::None.unwrap() + b"]
+        );
+    }
+
+    #[test]
+    fn test_signed() {
+        let source = "fn f(a: i128) -> i128 {\n    let b: i128 = a * 2;\n    let c = -a + 3_i64;\n    let d = if b < 0 { -b } else { b };\n    d.abs()\n}";
+        let file = parse(source);
+        assert_eq!(
+            mutated(&file, MutationType::UnaryMinus),
+            vec![
+                "    let c = a + 3_i64;",
+                "    let d = if b < 0 { b } else { b };"
+            ]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::SignedValue),
+            vec!["    let b: i128 = -(a * 2);", "    let c = -a + -3_i64;"]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::Abs),
+            vec!["    let d = b;", "    let d = -b;", "    d", "    -d"]
+        );
+    }
+
+    #[test]
+    fn test_loop_control() {
+        let source = "fn f(a: Span<u8>) -> u8 {\n    let mut i = 0;\n    loop {\n        if i == a.len() {\n            break;\n        }\n        i += 1_u32;\n        if i == 3 { continue; }\n        if *a.at(i) == 0 { break; }\n    };\n    while i < a.len() {\n        i += 2;\n    };\n    i += 1;\n    loop {\n        if a.len() == i {\n            break;\n        }\n        i += 1;\n    };\n    let j = loop {\n        if 3 == i {\n            break i;\n        }\n        i += 1;\n    };\n    j\n}";
+        let file = parse(source);
+        assert_eq!(
+            mutated(&file, MutationType::Break),
+            vec![
+                "            ",
+                "        if *a.at(i) == 0 {  }",
+                "            "
+            ]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::Continue),
+            vec!["        if i == 3 { break; }"]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::LoopStep),
+            vec!["        i += 2_u32;", "        i += 2;", "        i += 2;"]
+        );
+        assert_eq!(
+            mutated(&file, MutationType::LoopBound),
+            vec![
+                "        if i > a.len() {",
+                "    while i <= a.len() {",
+                "        if a.len() < i {",
+                "        if 3 < i {"
+            ]
+        );
     }
 }
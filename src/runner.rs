@@ -1,12 +1,15 @@
 use crate::{
     cli::print_result,
     file_manager::{collect_files_with_extension, get_tmp_dir},
+    lexer::Span,
     mutant::{Mutation, MutationResult, MutationType},
+    syntax::SourceFile,
     test_runner::{can_build, tests_successful},
     Result,
 };
 use rayon::prelude::*;
 use std::{
+    collections::HashMap,
     fs,
     path::{Path, PathBuf},
     sync::atomic::{AtomicUsize, Ordering},
@@ -24,25 +27,10 @@ pub fn run_mutation_checks(
             .expect("Couldn't collect files")
     };
 
-    let mutations_to_check: Vec<MutationType> = [
-        MutationType::Equal,
-        MutationType::NotEqual,
-        MutationType::GreaterThan,
-        MutationType::GreaterThanOrEqual,
-        MutationType::LessThan,
-        MutationType::LessThanOrEqual,
-        MutationType::Assert,
-        MutationType::IsZero,
-        MutationType::IsNonZero,
-        MutationType::And,
-        MutationType::Or,
-    ]
-    .into();
-
     let mutations: Vec<Mutation> =
-        collect_mutations(&source_folder_path, files, mutations_to_check);
+        collect_mutations(&source_folder_path, files, default_mutations());
 
-    if mutations.len() == 0 {
+    if mutations.is_empty() {
         println!("No mutations found");
         return Ok(());
     }
@@ -59,6 +47,82 @@ pub fn run_mutation_checks(
     print_result(results)
 }
 
+fn default_mutations() -> Vec<MutationType> {
+    vec![
+        MutationType::Equal,
+        MutationType::NotEqual,
+        MutationType::GreaterThan,
+        MutationType::GreaterThanOrEqual,
+        MutationType::LessThan,
+        MutationType::LessThanOrEqual,
+        MutationType::Assert,
+        MutationType::IsZero,
+        MutationType::IsNonZero,
+        MutationType::And,
+        MutationType::Or,
+        MutationType::Add,
+        MutationType::Sub,
+        MutationType::Mul,
+        MutationType::Div,
+        MutationType::Mod,
+        MutationType::AddAssign,
+        MutationType::SubAssign,
+        MutationType::MulAssign,
+        MutationType::DivAssign,
+        MutationType::BitAnd,
+        MutationType::BitOr,
+        MutationType::BitXor,
+        MutationType::ShiftLeft,
+        MutationType::ShiftRight,
+        MutationType::Not,
+        MutationType::Condition,
+        MutationType::True,
+        MutationType::False,
+        MutationType::FunctionBody,
+        MutationType::StorageWrite,
+        MutationType::Emit,
+        MutationType::AccessControl,
+        MutationType::CallerAddress,
+        MutationType::ContractAddress,
+        MutationType::BlockTimestamp,
+        MutationType::BlockNumber,
+        MutationType::IntegerLiteral,
+        MutationType::AssertMacro,
+        MutationType::AssertEq,
+        MutationType::AssertNe,
+        MutationType::AssertLt,
+        MutationType::AssertLe,
+        MutationType::AssertGt,
+        MutationType::AssertGe,
+        MutationType::OptionSome,
+        MutationType::ResultOk,
+        MutationType::Unwrap,
+        MutationType::IfElse,
+        MutationType::MatchArm,
+        MutationType::Break,
+        MutationType::Continue,
+        MutationType::LoopStep,
+        MutationType::LoopBound,
+        MutationType::EarlyReturn,
+        MutationType::Return,
+        MutationType::ErrorPropagation,
+        MutationType::Expect,
+        MutationType::ErrorMessage,
+        MutationType::DispatcherCall,
+        MutationType::DispatcherAddress,
+        MutationType::TryInto,
+        MutationType::Low,
+        MutationType::High,
+        MutationType::Append,
+        MutationType::Index,
+        MutationType::Len,
+        MutationType::PopFront,
+        MutationType::UnaryMinus,
+        MutationType::SignedValue,
+        MutationType::Abs,
+    ]
+}
+
 fn test_mutations(
     path_src: &Path,
     subfolder: String,
@@ -107,35 +171,63 @@ fn collect_mutations(
     mutations_to_check: Vec<MutationType>,
 ) -> Vec<Mutation> {
     let mut mutations: Vec<Mutation> = Vec::new();
+    let mut seen: HashMap<(PathBuf, Span, String), usize> = HashMap::new();
 
     // TODO Transform this into a map + collect
     for file in &files {
         // Read the content of the file into a string
-        let content = fs::read_to_string(&file).expect("Error while reading the file");
+        let content = fs::read_to_string(file).expect("Error while reading the file");
         let file_name = file.strip_prefix(path_src).expect("msg").to_path_buf();
+        // Test code (after `#[cfg(test)]`) is skipped by the source file
+        let source_file = SourceFile::new(file_name, content);
         // Look for mutation
-        for (pos, line) in content.lines().into_iter().enumerate() {
-            let line = line.to_string();
-            // We consider the rest of the file as test code
-            if line.contains("#[cfg(test)]") {
-                break;
-            }
-            for mutation in &mutations_to_check {
-                mutations.append(&mut mutation.others(file_name.clone(), line.clone(), pos));
+        for mutation in &mutations_to_check {
+            for other in mutation.others(&source_file) {
+                // Families can overlap, e.g. `<` => `<=` is also a loop bound mutation, the one
+                // with a category or a severity is kept
+                match seen.get(&other.key()) {
+                    Some(&idx) => {
+                        if is_flagged(&other) && !is_flagged(&mutations[idx]) {
+                            mutations[idx] = other;
+                        }
+                    }
+                    None => {
+                        seen.insert(other.key(), mutations.len());
+                        mutations.push(other);
+                    }
+                }
             }
         }
     }
     mutations
 }
 
+fn is_flagged(mutation: &Mutation) -> bool {
+    mutation.category().is_some() || mutation.is_high_severity()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{file_manager::collect_files_with_extension, mutant::MutationType};
 
-    use super::{collect_mutations, test_mutations, Mutation, MutationResult};
+    use super::{collect_mutations, default_mutations, test_mutations, Mutation, MutationResult};
     use rstest::rstest;
     use std::path::Path;
 
+    #[test]
+    fn test_overlapping_mutations_keep_category() {
+        let path_src = Path::new("test_data").join("accessControl");
+        let files = collect_files_with_extension(&path_src.join("src"), "cairo")
+            .expect("Couldn't collect files");
+        let mutations = collect_mutations(&path_src, files, default_mutations());
+        let guards: Vec<&Mutation> = mutations
+            .iter()
+            .filter(|m| m.category() == Some("Access control guards not tested"))
+            .collect();
+        assert_eq!(guards.len(), 3);
+        assert!(guards.iter().all(|m| m.is_high_severity()));
+    }
+
     #[rstest]
     #[case("equal", 1, MutationType::Equal)]
     #[case("notEqual", 1, MutationType::NotEqual)]
@@ -148,6 +240,66 @@ mod tests {
     #[case("isNonZero", 1, MutationType::IsNonZero)]
     #[case("and", 1, MutationType::And)]
     #[case("or", 1, MutationType::Or)]
+    #[case("equalMultiple", 2, MutationType::Equal)]
+    #[case("assertMultiline", 2, MutationType::Assert)]
+    #[case("add", 2, MutationType::Add)]
+    #[case("sub", 1, MutationType::Sub)]
+    #[case("mul", 2, MutationType::Mul)]
+    #[case("div", 2, MutationType::Div)]
+    #[case("mod", 1, MutationType::Mod)]
+    #[case("addAssign", 2, MutationType::AddAssign)]
+    #[case("subAssign", 1, MutationType::SubAssign)]
+    #[case("mulAssign", 1, MutationType::MulAssign)]
+    #[case("divAssign", 1, MutationType::DivAssign)]
+    #[case("bitAnd", 1, MutationType::BitAnd)]
+    #[case("bitOr", 1, MutationType::BitOr)]
+    #[case("bitXor", 1, MutationType::BitXor)]
+    #[case("shiftLeft", 1, MutationType::ShiftLeft)]
+    #[case("shiftRight", 1, MutationType::ShiftRight)]
+    #[case("not", 1, MutationType::Not)]
+    #[case("condition", 2, MutationType::Condition)]
+    #[case("true", 1, MutationType::True)]
+    #[case("false", 1, MutationType::False)]
+    #[case("functionBody", 7, MutationType::FunctionBody)]
+    #[case("storageWrite", 1, MutationType::StorageWrite)]
+    #[case("emit", 1, MutationType::Emit)]
+    #[case("accessControl", 3, MutationType::AccessControl)]
+    #[case("callerAddress", 1, MutationType::CallerAddress)]
+    #[case("contractAddress", 1, MutationType::ContractAddress)]
+    #[case("blockTimestamp", 2, MutationType::BlockTimestamp)]
+    #[case("blockNumber", 2, MutationType::BlockNumber)]
+    #[case("integerLiteral", 5, MutationType::IntegerLiteral)]
+    #[case("assertMacro", 1, MutationType::AssertMacro)]
+    #[case("assertEq", 2, MutationType::AssertEq)]
+    #[case("assertLt", 2, MutationType::AssertLt)]
+    #[case("optionSome", 1, MutationType::OptionSome)]
+    #[case("resultOk", 1, MutationType::ResultOk)]
+    #[case("unwrap", 1, MutationType::Unwrap)]
+    #[case("ifElse", 4, MutationType::IfElse)]
+    #[case("matchArm", 6, MutationType::MatchArm)]
+    #[case("loop", 2, MutationType::Break)]
+    #[case("loop", 1, MutationType::Continue)]
+    #[case("loop", 2, MutationType::LoopStep)]
+    #[case("loop", 2, MutationType::LoopBound)]
+    #[case("earlyReturn", 2, MutationType::EarlyReturn)]
+    #[case("return", 1, MutationType::Return)]
+    #[case("errorPropagation", 1, MutationType::ErrorPropagation)]
+    #[case("expect", 1, MutationType::Expect)]
+    #[case("errorMessage", 2, MutationType::ErrorMessage)]
+    #[case("dispatcher", 1, MutationType::DispatcherCall)]
+    #[case("dispatcher", 1, MutationType::DispatcherAddress)]
+    #[case("dispatcherLet", 1, MutationType::DispatcherCall)]
+    #[case("dispatcherLet", 1, MutationType::DispatcherAddress)]
+    #[case("conversion", 2, MutationType::TryInto)]
+    #[case("conversion", 1, MutationType::Low)]
+    #[case("conversion", 1, MutationType::High)]
+    #[case("array", 1, MutationType::Append)]
+    #[case("array", 2, MutationType::Index)]
+    #[case("array", 2, MutationType::Len)]
+    #[case("array", 1, MutationType::PopFront)]
+    #[case("signed", 2, MutationType::UnaryMinus)]
+    #[case("signed", 1, MutationType::SignedValue)]
+    #[case("signed", 2, MutationType::Abs)]
     fn test_success(
         #[case] folder: String,
         #[case] len: usize,
@@ -177,6 +329,66 @@ mod tests {
     #[case("isNonZeroFail", 1, MutationType::IsNonZero)]
     #[case("andFail", 1, MutationType::And)]
     #[case("orFail", 1, MutationType::Or)]
+    #[case("equalMultipleFail", 2, MutationType::Equal)]
+    #[case("assertMultilineFail", 2, MutationType::Assert)]
+    #[case("addFail", 2, MutationType::Add)]
+    #[case("subFail", 1, MutationType::Sub)]
+    #[case("mulFail", 2, MutationType::Mul)]
+    #[case("divFail", 2, MutationType::Div)]
+    #[case("modFail", 1, MutationType::Mod)]
+    #[case("addAssignFail", 2, MutationType::AddAssign)]
+    #[case("subAssignFail", 1, MutationType::SubAssign)]
+    #[case("mulAssignFail", 1, MutationType::MulAssign)]
+    #[case("divAssignFail", 1, MutationType::DivAssign)]
+    #[case("bitAndFail", 1, MutationType::BitAnd)]
+    #[case("bitOrFail", 1, MutationType::BitOr)]
+    #[case("bitXorFail", 1, MutationType::BitXor)]
+    #[case("shiftLeftFail", 1, MutationType::ShiftLeft)]
+    #[case("shiftRightFail", 1, MutationType::ShiftRight)]
+    #[case("notFail", 1, MutationType::Not)]
+    #[case("conditionFail", 2, MutationType::Condition)]
+    #[case("trueFail", 1, MutationType::True)]
+    #[case("falseFail", 1, MutationType::False)]
+    #[case("functionBodyFail", 7, MutationType::FunctionBody)]
+    #[case("storageWriteFail", 1, MutationType::StorageWrite)]
+    #[case("emitFail", 1, MutationType::Emit)]
+    #[case("accessControlFail", 3, MutationType::AccessControl)]
+    #[case("callerAddressFail", 1, MutationType::CallerAddress)]
+    #[case("contractAddressFail", 1, MutationType::ContractAddress)]
+    #[case("blockTimestampFail", 2, MutationType::BlockTimestamp)]
+    #[case("blockNumberFail", 2, MutationType::BlockNumber)]
+    #[case("integerLiteralFail", 5, MutationType::IntegerLiteral)]
+    #[case("assertMacroFail", 1, MutationType::AssertMacro)]
+    #[case("assertEqFail", 2, MutationType::AssertEq)]
+    #[case("assertLtFail", 2, MutationType::AssertLt)]
+    #[case("optionSomeFail", 1, MutationType::OptionSome)]
+    #[case("resultOkFail", 1, MutationType::ResultOk)]
+    #[case("unwrapFail", 1, MutationType::Unwrap)]
+    #[case("ifElseFail", 4, MutationType::IfElse)]
+    #[case("matchArmFail", 6, MutationType::MatchArm)]
+    #[case("loopFail", 2, MutationType::Break)]
+    #[case("loopFail", 1, MutationType::Continue)]
+    #[case("loopFail", 2, MutationType::LoopStep)]
+    #[case("loopFail", 2, MutationType::LoopBound)]
+    #[case("earlyReturnFail", 2, MutationType::EarlyReturn)]
+    #[case("returnFail", 1, MutationType::Return)]
+    #[case("errorPropagationFail", 1, MutationType::ErrorPropagation)]
+    #[case("expectFail", 1, MutationType::Expect)]
+    #[case("errorMessageFail", 2, MutationType::ErrorMessage)]
+    #[case("dispatcherFail", 1, MutationType::DispatcherCall)]
+    #[case("dispatcherFail", 1, MutationType::DispatcherAddress)]
+    #[case("dispatcherLetFail", 1, MutationType::DispatcherCall)]
+    #[case("dispatcherLetFail", 1, MutationType::DispatcherAddress)]
+    #[case("conversionFail", 2, MutationType::TryInto)]
+    #[case("conversionFail", 1, MutationType::Low)]
+    #[case("conversionFail", 1, MutationType::High)]
+    #[case("arrayFail", 1, MutationType::Append)]
+    #[case("arrayFail", 2, MutationType::Index)]
+    #[case("arrayFail", 2, MutationType::Len)]
+    #[case("arrayFail", 1, MutationType::PopFront)]
+    #[case("signedFail", 2, MutationType::UnaryMinus)]
+    #[case("signedFail", 1, MutationType::SignedValue)]
+    #[case("signedFail", 2, MutationType::Abs)]
     fn test_failure(
         #[case] folder: String,
         #[case] len: usize,
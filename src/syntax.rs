@@ -0,0 +1,804 @@
+use crate::lexer::{tokenize, Span, Token, TokenKind};
+use std::path::PathBuf;
+
+const KEYWORDS: [&str; 24] = [
+    "as", "break", "const", "continue", "else", "enum", "fn", "for", "if", "impl", "in", "let",
+    "loop", "match", "mod", "mut", "pub", "ref", "return", "struct", "trait", "type", "use",
+    "while",
+];
+
+// Indexes of the opening and closing braces of a block
+pub type Block = (usize, usize);
+
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub name: String,
+    // From `fn` to the closing brace
+    pub span: Span,
+    // Braces included
+    pub body: Span,
+    // Token index of the opening brace of the body
+    pub body_open: usize,
+    pub return_type: Option<String>,
+    pub is_test: bool,
+}
+
+// `pattern => body` arm of a match, as first and last token indexes
+#[derive(Debug, Clone, Copy)]
+pub struct Arm {
+    pub pattern: (usize, usize),
+    pub body: (usize, usize),
+    // Trailing comma if any, else the end of the body
+    pub end: usize,
+}
+
+impl Arm {
+    pub fn is_wildcard(&self, file: &SourceFile) -> bool {
+        self.pattern.0 == self.pattern.1 && file.text(self.pattern.0) == "_"
+    }
+}
+
+// Cairo file split into significant tokens with enough structure to locate mutations
+#[derive(Debug)]
+pub struct SourceFile {
+    pub file_name: PathBuf,
+    pub source: String,
+    pub tokens: Vec<Token>,
+    pub functions: Vec<Function>,
+    pairs: Vec<Option<usize>>,
+    generics: Vec<bool>,
+    // `#[starknet::contract]` and `#[starknet::component]` modules
+    contracts: Vec<Span>,
+    // `#[...]` attributes
+    attributes: Vec<Span>,
+    // Index of the first token of test code, everything after it is ignored
+    test_start: usize,
+}
+
+impl SourceFile {
+    pub fn new(file_name: PathBuf, source: String) -> Self {
+        let tokens: Vec<Token> = tokenize(&source)
+            .into_iter()
+            .filter(|t| !t.is_trivia())
+            .collect();
+        let len = tokens.len();
+        let mut file = SourceFile {
+            file_name,
+            source,
+            tokens,
+            functions: Vec::new(),
+            pairs: vec![None; len],
+            generics: vec![false; len],
+            contracts: Vec::new(),
+            attributes: Vec::new(),
+            test_start: len,
+        };
+        file.match_delimiters();
+        file.find_generics();
+        file.find_test_start();
+        file.find_functions();
+        file.find_contracts();
+        file.find_attributes();
+        file
+    }
+
+    pub fn text(&self, idx: usize) -> &str {
+        self.tokens[idx].text(&self.source)
+    }
+
+    // Bound checked comparison of the token text
+    pub fn is(&self, idx: usize, text: &str) -> bool {
+        idx < self.tokens.len() && self.text(idx) == text
+    }
+
+    // Tokens starting at idx have exactly these texts
+    pub fn is_sequence(&self, idx: usize, texts: &[&str]) -> bool {
+        texts.iter().enumerate().all(|(i, t)| self.is(idx + i, t))
+    }
+
+    // First token of the path leading to idx, e.g. `starknet` in `starknet::get_caller_address`
+    pub fn path_start(&self, idx: usize) -> usize {
+        let mut first = idx;
+        while first >= 2 && self.text(first - 1) == "::" && self.kind(first - 2) == TokenKind::Ident
+        {
+            first -= 2;
+        }
+        first
+    }
+
+    pub fn kind(&self, idx: usize) -> TokenKind {
+        self.tokens[idx].kind
+    }
+
+    pub fn matching(&self, idx: usize) -> Option<usize> {
+        self.pairs[idx]
+    }
+
+    // `<` or `>` used as a generic bracket rather than a comparison
+    pub fn is_generic(&self, idx: usize) -> bool {
+        self.generics[idx]
+    }
+
+    // Tokens that can be mutated, test code is left untouched
+    pub fn code_len(&self) -> usize {
+        self.test_start
+    }
+
+    pub fn span(&self, first: usize, last: usize) -> Span {
+        Span::new(self.tokens[first].span.start, self.tokens[last].span.end)
+    }
+
+    pub fn slice(&self, span: Span) -> &str {
+        &self.source[span.start..span.end]
+    }
+
+    pub fn function_at(&self, offset: usize) -> Option<&Function> {
+        self.functions
+            .iter()
+            .find(|f| f.span.start <= offset && offset < f.span.end)
+    }
+
+    pub fn in_contract(&self, offset: usize) -> bool {
+        self.contracts
+            .iter()
+            .any(|c| c.start <= offset && offset < c.end)
+    }
+
+    pub fn in_attribute(&self, idx: usize) -> bool {
+        let offset = self.tokens[idx].span.start;
+        self.attributes
+            .iter()
+            .any(|a| a.start <= offset && offset < a.end)
+    }
+
+    // Name of the `const` item containing idx
+    pub fn const_name(&self, idx: usize) -> Option<&str> {
+        let mut i = idx;
+        while i > 0 && !self.is_statement_start(i) {
+            i -= 1;
+        }
+        if self.is(i, "pub") {
+            i += 1;
+        }
+        (self.is(i, "const") && i + 1 < self.tokens.len()).then(|| self.text(i + 1))
+    }
+
+    // Item referenced in test code but nowhere else, besides its declaration
+    pub fn used_only_in_tests(&self, name: &str) -> bool {
+        let uses: Vec<usize> = (0..self.tokens.len())
+            .filter(|&i| self.text(i) == name && !(i > 0 && self.text(i - 1) == "const"))
+            .collect();
+        !uses.is_empty() && uses.iter().all(|&i| i >= self.test_start)
+    }
+
+    // Function declared by the `fn` token at idx
+    pub fn function_of(&self, idx: usize) -> Option<&Function> {
+        let start = self.tokens[idx].span.start;
+        self.functions.iter().find(|f| f.span.start == start)
+    }
+
+    // Leading whitespace of the line containing the offset
+    pub fn indentation(&self, offset: usize) -> &str {
+        let line = self.slice(self.lines_span(Span::new(offset, offset)));
+        &line[..line.len() - line.trim_start().len()]
+    }
+
+    // 0-based line of a byte offset
+    pub fn line_of(&self, offset: usize) -> usize {
+        self.source[..offset].matches('\n').count()
+    }
+
+    // Byte range of the full lines covering the span
+    pub fn lines_span(&self, span: Span) -> Span {
+        let start = self.source[..span.start].rfind('\n').map_or(0, |i| i + 1);
+        let end = self.source[span.end..]
+            .find('\n')
+            .map_or(self.source.len(), |i| span.end + i);
+        Span::new(start, end)
+    }
+
+    // Operator with an operand on its left, unlike `-x`, `*x` (desnap), a bound `+Drop<T>`, the
+    // `|` between patterns of a match arm or the one closing the parameters of a closure
+    pub fn is_binary(&self, idx: usize) -> bool {
+        if idx == 0
+            || (self.text(idx) == "|" && (self.in_arm_pattern(idx) || self.closes_params(idx)))
+        {
+            return false;
+        }
+        match self.kind(idx - 1) {
+            TokenKind::Ident => !KEYWORDS.contains(&self.text(idx - 1)),
+            TokenKind::Number | TokenKind::ShortString | TokenKind::String => true,
+            _ => matches!(self.text(idx - 1), ")" | "]" | "?"),
+        }
+    }
+
+    // `<<` or `>>` starting at idx, two glued tokens since the lexer leaves them split
+    pub fn shift_at(&self, idx: usize) -> Option<&str> {
+        let text = self.text(idx);
+        if !matches!(text, "<" | ">")
+            || !self.is(idx + 1, text)
+            || self.tokens[idx].span.end != self.tokens[idx + 1].span.start
+            || self.is_generic(idx)
+            || !self.is_binary(idx)
+        {
+            return None;
+        }
+        Some(if text == "<" { "<<" } else { ">>" })
+    }
+
+    pub fn is_shift(&self, idx: usize) -> bool {
+        self.shift_at(idx).is_some() || (idx > 0 && self.shift_at(idx - 1).is_some())
+    }
+
+    // Binding power of the binary operator at idx and its length in tokens
+    fn binary_operator(&self, idx: usize) -> Option<(u8, usize)> {
+        if idx >= self.tokens.len() || !self.is_binary(idx) {
+            return None;
+        }
+        if self.shift_at(idx).is_some() {
+            return Some((8, 2));
+        }
+        let precedence = match self.text(idx) {
+            "*" | "/" | "%" => 10,
+            "+" | "-" => 9,
+            "&" => 7,
+            "^" => 6,
+            "|" => 5,
+            "==" | "!=" | ">=" | "<=" => 4,
+            "<" | ">" if !self.is_generic(idx) => 4,
+            "&&" => 3,
+            "||" => 2,
+            _ => return None,
+        };
+        Some((precedence, 1))
+    }
+
+    // Last token of the operand starting at idx: prefixes, a path, literal or group and its
+    // postfix calls, fields, indexes and `?`
+    pub fn operand_end(&self, idx: usize) -> Option<usize> {
+        let mut i = idx;
+        while i < self.tokens.len() && matches!(self.text(i), "-" | "!" | "*" | "@") {
+            i += 1;
+        }
+        if i >= self.tokens.len() {
+            return None;
+        }
+        let mut end = match (self.kind(i), self.text(i)) {
+            (_, "(" | "[") => self.matching(i)?,
+            (TokenKind::Ident, text) if !KEYWORDS.contains(&text) => i,
+            (TokenKind::Number | TokenKind::ShortString | TokenKind::String, _) => i,
+            _ => return None,
+        };
+        loop {
+            let next = end + 1;
+            if next >= self.tokens.len() {
+                break;
+            }
+            end = match self.text(next) {
+                "." | "::" if next + 1 < self.tokens.len() => {
+                    if self.is(next + 1, "<") {
+                        self.generic_close(next + 1)?
+                    } else {
+                        next + 1
+                    }
+                }
+                "(" | "[" => self.matching(next)?,
+                "!" if self.is(next + 1, "(") || self.is(next + 1, "[") => {
+                    self.matching(next + 1)?
+                }
+                "?" => next,
+                _ => break,
+            };
+        }
+        Some(end)
+    }
+
+    // First token of the expression the `.` at idx follows, e.g. `self` in
+    // `self.balance.read().try_into()`
+    pub fn receiver_start(&self, idx: usize) -> Option<usize> {
+        if !self.is(idx, ".") {
+            return None;
+        }
+        let mut start = idx.checked_sub(1)?;
+        loop {
+            if matches!(self.text(start), ")" | "]") {
+                let open = self.matching(start)?;
+                // Called or indexed expression, not a parenthesized group
+                if open > 0 && self.is_binary(open) {
+                    start = open - 1;
+                    continue;
+                }
+                start = open;
+            } else if !matches!(self.kind(start), TokenKind::Ident | TokenKind::Number) {
+                return None;
+            }
+            if start >= 2 && matches!(self.text(start - 1), "." | "::") {
+                start -= 2;
+            } else {
+                return Some(start);
+            }
+        }
+    }
+
+    // Last token of the right operand of the binary operator at idx
+    pub fn right_operand_end(&self, idx: usize) -> Option<usize> {
+        let (precedence, len) = self.binary_operator(idx)?;
+        let mut end = self.operand_end(idx + len)?;
+        while let Some((next, len)) = self.binary_operator(end + 1) {
+            if next <= precedence {
+                break;
+            }
+            end = self.operand_end(end + 1 + len)?;
+        }
+        Some(end)
+    }
+
+    // First and last tokens of the condition of an `if` or a `while`, or the first argument of
+    // an `assert` or `assert!`
+    pub fn condition(&self, idx: usize) -> Option<(usize, usize)> {
+        match self.text(idx) {
+            "if" | "while" if !self.is(idx + 1, "let") => {
+                let mut i = idx + 1;
+                while i < self.tokens.len() && self.text(i) != "{" {
+                    if matches!(self.text(i), "(" | "[") {
+                        i = self.matching(i)?;
+                    }
+                    i += 1;
+                }
+                (i > idx + 1 && i < self.tokens.len()).then_some((idx + 1, i - 1))
+            }
+            "assert" => {
+                // `assert(...)` or the `assert!(...)` macro
+                let open = if self.is(idx + 1, "!") {
+                    idx + 2
+                } else {
+                    idx + 1
+                };
+                if !self.is(open, "(") {
+                    return None;
+                }
+                let close = self.matching(open)?;
+                let mut i = open + 1;
+                while i < close && self.text(i) != "," {
+                    if matches!(self.text(i), "(" | "[" | "{") {
+                        i = self.matching(i)?;
+                    }
+                    i += 1;
+                }
+                (i > open + 1).then_some((open + 1, i - 1))
+            }
+            _ => None,
+        }
+    }
+
+    // Opening and closing braces of the blocks of the `if` at idx, the `else` one only when
+    // it is a plain block and not an `else if`
+    pub fn branches(&self, idx: usize) -> Option<(Block, Option<Block>)> {
+        let (_, last) = self.condition(idx)?;
+        let then_open = last + 1;
+        let then_close = self.matching(then_open)?;
+        let otherwise = if self.is_sequence(then_close + 1, &["else", "{"]) {
+            Some((then_close + 2, self.matching(then_close + 2)?))
+        } else {
+            None
+        };
+        Some(((then_open, then_close), otherwise))
+    }
+
+    // Arms of the `match` at idx
+    pub fn arms(&self, idx: usize) -> Option<Vec<Arm>> {
+        if self.text(idx) != "match" {
+            return None;
+        }
+        let open = self.skip_to(idx + 1, self.tokens.len(), "{")?;
+        let close = self.matching(open)?;
+        let mut arms = Vec::new();
+        let mut first = open + 1;
+        while first < close {
+            let arrow = self.skip_to(first, close, "=>")?;
+            let last = if self.is(arrow + 1, "{") {
+                self.matching(arrow + 1)?
+            } else {
+                self.skip_to(arrow + 1, close, ",").unwrap_or(close) - 1
+            };
+            if arrow == first || last <= arrow {
+                return None;
+            }
+            let end = if self.is(last + 1, ",") {
+                last + 1
+            } else {
+                last
+            };
+            arms.push(Arm {
+                pattern: (first, arrow - 1),
+                body: (arrow + 1, last),
+                end,
+            });
+            first = end + 1;
+        }
+        Some(arms)
+    }
+
+    // `|` at idx closes the parameters of a closure, like the second one of `|x: u8| x + 1`
+    fn closes_params(&self, idx: usize) -> bool {
+        let Some(open) = (0..idx)
+            .rev()
+            .take_while(|&k| !matches!(self.text(k), ";" | "{" | "}"))
+            .find(|&k| self.text(k) == "|")
+        else {
+            return false;
+        };
+        open == 0
+            || matches!(self.text(open - 1), "(" | "=" | ",")
+            || KEYWORDS.contains(&self.text(open - 1))
+    }
+
+    // Inside the pattern of a match arm, like the `|` of `E::A | E::B => ...`
+    pub fn in_arm_pattern(&self, idx: usize) -> bool {
+        (0..idx)
+            .filter(|&k| self.text(k) == "match")
+            .filter_map(|k| self.arms(k))
+            .flatten()
+            .any(|arm| arm.pattern.0 <= idx && idx <= arm.pattern.1)
+    }
+
+    // Inside the body of a `loop`, `while` or `for`
+    pub fn in_loop(&self, idx: usize) -> bool {
+        self.enclosing_loop(idx).is_some()
+    }
+
+    // Body of the innermost `loop`, `while` or `for` containing idx
+    pub fn enclosing_loop(&self, idx: usize) -> Option<Block> {
+        (0..idx)
+            .rev()
+            .filter(|&k| matches!(self.text(k), "loop" | "while" | "for"))
+            .find_map(|k| {
+                let open = self.skip_to(k + 1, idx, "{")?;
+                let close = self.matching(open)?;
+                (idx < close).then_some((open, close))
+            })
+    }
+
+    // First token with this text from idx and before limit, outside of nested delimiters
+    fn skip_to(&self, idx: usize, limit: usize, text: &str) -> Option<usize> {
+        let mut i = idx;
+        while i < limit && self.text(i) != text {
+            if matches!(self.text(i), "(" | "[" | "{") {
+                i = self.matching(i)?;
+            }
+            i += 1;
+        }
+        (i < limit).then_some(i)
+    }
+
+    pub fn is_statement_start(&self, idx: usize) -> bool {
+        idx == 0 || matches!(self.text(idx - 1), "{" | "}" | ";")
+    }
+
+    // Index of the `;` ending the statement starting at idx, None if it is a tail expression
+    pub fn statement_end(&self, idx: usize) -> Option<usize> {
+        let mut i = idx;
+        while i < self.tokens.len() {
+            match self.text(i) {
+                ";" => return Some(i),
+                "(" | "[" | "{" => i = self.matching(i)?,
+                ")" | "]" | "}" => return None,
+                _ => {}
+            }
+            i += 1;
+        }
+        None
+    }
+
+    // Last token and replacement to remove the expression `first..=last`, the trailing `;` of a
+    // statement goes with it while a match arm body or the tail expression of a block becomes `()`
+    pub fn removal(&self, first: usize, last: usize) -> Option<(usize, &'static str)> {
+        if self.is_statement_start(first) && self.is(last + 1, ";") {
+            Some((last + 1, ""))
+        } else if (self.is_statement_start(first) && self.is(last + 1, "}"))
+            || (first > 0
+                && self.text(first - 1) == "=>"
+                && (self.is(last + 1, ",") || self.is(last + 1, "}")))
+        {
+            Some((last, "()"))
+        } else {
+            None
+        }
+    }
+
+    fn match_delimiters(&mut self) {
+        let mut stack: Vec<usize> = Vec::new();
+        for idx in 0..self.tokens.len() {
+            match self.text(idx) {
+                "(" | "[" | "{" => stack.push(idx),
+                ")" | "]" | "}" => {
+                    if let Some(open) = stack.pop() {
+                        self.pairs[open] = Some(idx);
+                        self.pairs[idx] = Some(open);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // A `<` glued to a path segment and closed by `>` with only type tokens in between
+    fn find_generics(&mut self) {
+        for idx in 1..self.tokens.len() {
+            if self.generics[idx] || self.text(idx) != "<" {
+                continue;
+            }
+            let prev = &self.tokens[idx - 1];
+            if prev.span.end != self.tokens[idx].span.start
+                || !(prev.kind == TokenKind::Ident || self.text(idx - 1) == "::")
+            {
+                continue;
+            }
+            if let Some(close) = self.generic_close(idx) {
+                for i in idx..=close {
+                    if matches!(self.text(i), "<" | ">") {
+                        self.generics[i] = true;
+                    }
+                }
+            }
+        }
+    }
+
+    fn generic_close(&self, open: usize) -> Option<usize> {
+        let mut depth = 0;
+        let mut i = open;
+        while i < self.tokens.len() {
+            match (self.kind(i), self.text(i)) {
+                (_, "<") => depth += 1,
+                (_, ">") => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(i);
+                    }
+                }
+                (_, "(") | (_, "[") => i = self.matching(i)?,
+                (TokenKind::Ident, _) | (TokenKind::Number, _) => {}
+                (_, "::" | "," | ":" | "=" | "+" | "-" | "@") => {}
+                _ => return None,
+            }
+            i += 1;
+        }
+        None
+    }
+
+    fn find_test_start(&mut self) {
+        let cfg_test = ["#", "[", "cfg", "(", "test", ")", "]"];
+        if let Some(idx) = (0..self.tokens.len()).find(|&idx| self.is_sequence(idx, &cfg_test)) {
+            self.test_start = idx;
+        }
+    }
+
+    fn find_attributes(&mut self) {
+        self.attributes = (0..self.tokens.len())
+            .filter(|&idx| self.is_sequence(idx, &["#", "["]))
+            .filter_map(|idx| Some(self.span(idx, self.matching(idx + 1)?)))
+            .collect();
+    }
+
+    fn find_contracts(&mut self) {
+        let mut contracts = Vec::new();
+        for idx in 0..self.tokens.len() {
+            if !self.is_sequence(idx, &["#", "[", "starknet", "::"])
+                || !(self.is_sequence(idx + 4, &["contract", "]"])
+                    || self.is_sequence(idx + 4, &["component", "]"]))
+            {
+                continue;
+            }
+            let Some(module) = (idx + 6..self.tokens.len()).find(|&i| self.text(i) == "mod") else {
+                continue;
+            };
+            if !self.is(module + 2, "{") {
+                continue;
+            }
+            if let Some(close) = self.matching(module + 2) {
+                contracts.push(self.span(module, close));
+            }
+        }
+        self.contracts = contracts;
+    }
+
+    fn find_functions(&mut self) {
+        let mut functions = Vec::new();
+        for idx in 0..self.tokens.len() {
+            if self.text(idx) != "fn"
+                || self.tokens.get(idx + 1).map(|t| t.kind) != Some(TokenKind::Ident)
+            {
+                continue;
+            }
+            if let Some(function) = self.function(idx) {
+                functions.push(function);
+            }
+        }
+        self.functions = functions;
+    }
+
+    fn function(&self, fn_idx: usize) -> Option<Function> {
+        let params = (fn_idx + 2..self.tokens.len()).find(|&i| self.text(i) == "(")?;
+        let mut i = self.matching(params)? + 1;
+        let mut return_type = None;
+        if self.is(i, "->") {
+            let start = i + 1;
+            while i < self.tokens.len() && !matches!(self.text(i), "{" | ";") {
+                if self.text(i) == "(" {
+                    i = self.matching(i)?;
+                }
+                i += 1;
+            }
+            // Drop the `nopanic`/`implicits` clauses if any
+            let end = (start..i)
+                .find(|&j| matches!(self.text(j), "nopanic" | "implicits"))
+                .unwrap_or(i);
+            return_type = Some(self.slice(self.span(start, end - 1)).to_string());
+        } else {
+            i = (i..self.tokens.len()).find(|&j| matches!(self.text(j), "{" | ";"))?;
+        }
+        if self.text(i) != "{" {
+            return None;
+        }
+        let close = self.matching(i)?;
+        Some(Function {
+            name: self.text(fn_idx + 1).to_string(),
+            span: self.span(fn_idx, close),
+            body: self.span(i, close),
+            body_open: i,
+            return_type,
+            is_test: self.has_test_attribute(fn_idx),
+        })
+    }
+
+    fn has_test_attribute(&self, fn_idx: usize) -> bool {
+        let mut i = fn_idx;
+        if i > 0 && self.text(i - 1) == "pub" {
+            i -= 1;
+        }
+        while i > 1 && self.text(i - 1) == "]" {
+            let Some(open) = self.matching(i - 1) else {
+                return false;
+            };
+            if self.is(open + 1, "test") {
+                return true;
+            }
+            i = open - 1;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SourceFile;
+    use std::path::PathBuf;
+
+    fn parse(source: &str) -> SourceFile {
+        SourceFile::new(PathBuf::from("lib.cairo"), source.to_string())
+    }
+
+    #[test]
+    fn test_generics() {
+        let file = parse("fn a(x: Array<Array<u8>>) -> bool { x.len() < 2 && 1 > 0 }");
+        let angles: Vec<bool> = (0..file.tokens.len())
+            .filter(|&i| matches!(file.text(i), "<" | ">"))
+            .map(|i| file.is_generic(i))
+            .collect();
+        assert_eq!(angles, vec![true, true, true, true, false, false]);
+    }
+
+    #[test]
+    fn test_functions() {
+        let file = parse(
+            "fn a(x: u8) -> (u8, bool) {\n    (x, true)\n}\n\n#[cfg(test)]\nmod tests {\n    #[test]\n    fn b() {}\n}",
+        );
+        assert_eq!(file.functions.len(), 2);
+        assert_eq!(file.functions[0].name, "a");
+        assert_eq!(file.functions[0].return_type.as_deref(), Some("(u8, bool)"));
+        assert!(!file.functions[0].is_test);
+        assert!(file.functions[1].is_test);
+        assert!(file.code_len() < file.tokens.len());
+        assert_eq!(
+            file.function_at(file.source.find("true").unwrap())
+                .unwrap()
+                .name,
+            "a"
+        );
+    }
+
+    #[test]
+    fn test_is_binary() {
+        let file = parse("fn a<T, +Drop<T>>(x: @u8) -> u8 { let y = -*x; y - 1 * (y + 2) }");
+        let binaries: Vec<bool> = (0..file.tokens.len())
+            .filter(|&i| matches!(file.text(i), "+" | "-" | "*"))
+            .map(|i| file.is_binary(i))
+            .collect();
+        assert_eq!(binaries, vec![false, false, false, true, true, true]);
+        let file = parse("fn a(x: i8) -> i8 { if x < 0 { return -x; } x - -1 }");
+        let binaries: Vec<bool> = (0..file.tokens.len())
+            .filter(|&i| file.is(i, "-"))
+            .map(|i| file.is_binary(i))
+            .collect();
+        assert_eq!(binaries, vec![false, true, false]);
+    }
+
+    #[test]
+    fn test_operands() {
+        let file = parse("fn a() { x << 2 >> 1 ^ y.z(1)[0] * 2 & 3 | Array::<u8>::new() }");
+        let find = |text: &str| (0..file.tokens.len()).find(|&i| file.is(i, text)).unwrap();
+        assert!(file.is_shift(find("<")));
+        assert!(file.is_shift(find("<") + 1));
+        let xor = find("^");
+        assert_eq!(file.text(file.right_operand_end(xor).unwrap()), "3");
+        let or = find("|");
+        let end = file.right_operand_end(or).unwrap();
+        assert_eq!(file.slice(file.span(or + 1, end)), "Array::<u8>::new()");
+    }
+
+    #[test]
+    fn test_contracts() {
+        let file = parse("fn a() {}\n#[starknet::contract]\npub mod C {\n    fn b() {}\n}");
+        assert!(!file.in_contract(file.functions[0].span.start));
+        assert!(file.in_contract(file.functions[1].span.start));
+    }
+
+    #[test]
+    fn test_consts() {
+        let file = parse("const A: u8 = 1;\npub const B: u8 = 2;\n#[available_gas(10)]\nfn a() -> u8 {\n    A\n}\n#[cfg(test)]\nmod tests {\n    fn b() -> u8 {\n        super::A + super::B\n    }\n}");
+        let find = |text: &str| (0..file.tokens.len()).find(|&i| file.is(i, text)).unwrap();
+        assert_eq!(file.const_name(find("1")), Some("A"));
+        assert_eq!(file.const_name(find("2")), Some("B"));
+        assert_eq!(file.const_name(find("10")), None);
+        assert!(file.in_attribute(find("10")));
+        assert!(!file.used_only_in_tests("A"));
+        assert!(file.used_only_in_tests("B"));
+    }
+
+    #[test]
+    fn test_statement_end() {
+        let file = parse("fn a() { assert(x, 'a;'); x }");
+        let assert = (0..file.tokens.len())
+            .find(|&i| file.is(i, "assert"))
+            .unwrap();
+        assert!(file.is_statement_start(assert));
+        assert_eq!(file.statement_end(assert).map(|i| file.text(i)), Some(";"));
+        let x = file.tokens.len() - 2;
+        assert_eq!(file.statement_end(x), None);
+    }
+
+    #[test]
+    fn test_receiver_start() {
+        let file = parse("fn f() {\n    let a = self.b.read().try_into();\n    let c = (d + e).try_into();\n    let f = g::h(i)[0].try_into();\n}");
+        let starts: Vec<&str> = (0..file.tokens.len())
+            .filter(|&i| file.is(i + 1, "try_into"))
+            .map(|i| file.text(file.receiver_start(i).unwrap()))
+            .collect();
+        assert_eq!(starts, vec!["self", "(", "g"]);
+    }
+
+    #[test]
+    fn test_arms() {
+        let file = parse("fn f(a: E) -> u8 {\n    match a {\n        E::A(x) => x,\n        E::B => { 1 }\n        _ => f(1, 2)\n    }\n}");
+        let idx = (0..file.tokens.len())
+            .find(|&i| file.is(i, "match"))
+            .unwrap();
+        let arms = file.arms(idx).unwrap();
+        let texts: Vec<(&str, &str)> = arms
+            .iter()
+            .map(|arm| {
+                (
+                    file.slice(file.span(arm.pattern.0, arm.pattern.1)),
+                    file.slice(file.span(arm.body.0, arm.body.1)),
+                )
+            })
+            .collect();
+        assert_eq!(
+            texts,
+            vec![("E::A(x)", "x"), ("E::B", "{ 1 }"), ("_", "f(1, 2)")]
+        );
+        assert!(file.is(arms[0].end, ","));
+        assert!(arms[2].is_wildcard(&file));
+        assert!(!arms[1].is_wildcard(&file));
+    }
+}
@@ -0,0 +1,50 @@
+#[starknet::contract]
+mod Vault {
+    use starknet::{ContractAddress, get_caller_address};
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+
+    #[storage]
+    struct Storage {
+        owner: ContractAddress,
+        value: u128,
+    }
+
+    #[generate_trait]
+    impl InternalImpl of InternalTrait {
+        fn assert_only_owner(self: @ContractState) {
+            assert(get_caller_address() == self.owner.read(), 'Not owner');
+        }
+
+        fn set(ref self: ContractState, value: u128) {
+            self.assert_only_owner();
+            self.value.write(value);
+        }
+
+        fn reset(ref self: ContractState) {
+            assert(get_caller_address() == self.owner.read(), 'Not owner');
+            self.value.write(0);
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{contract_state_for_testing, InternalImpl};
+        use starknet::{contract_address_const, testing::set_caller_address};
+
+        #[test]
+        #[should_panic(expected: ('Not owner',))]
+        fn test_set() {
+            let mut state = contract_state_for_testing();
+            set_caller_address(contract_address_const::<1>());
+            InternalImpl::set(ref state, 2);
+        }
+
+        #[test]
+        #[should_panic(expected: ('Not owner',))]
+        fn test_reset() {
+            let mut state = contract_state_for_testing();
+            set_caller_address(contract_address_const::<1>());
+            InternalImpl::reset(ref state);
+        }
+    }
+}
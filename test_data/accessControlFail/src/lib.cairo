@@ -0,0 +1,28 @@
+#[starknet::contract]
+mod Vault {
+    use starknet::{ContractAddress, get_caller_address};
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+
+    #[storage]
+    struct Storage {
+        owner: ContractAddress,
+        value: u128,
+    }
+
+    #[generate_trait]
+    impl InternalImpl of InternalTrait {
+        fn assert_only_owner(self: @ContractState) {
+            assert(get_caller_address() == self.owner.read(), 'Not owner');
+        }
+
+        fn set(ref self: ContractState, value: u128) {
+            self.assert_only_owner();
+            self.value.write(value);
+        }
+
+        fn reset(ref self: ContractState) {
+            assert(get_caller_address() == self.owner.read(), 'Not owner');
+            self.value.write(0);
+        }
+    }
+}
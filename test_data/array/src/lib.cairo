@@ -0,0 +1,43 @@
+fn evens(values: Span<u8>) -> Array<u8> {
+    let mut result = array![];
+    let mut i = 0;
+    while i < values.len() {
+        let value = *values.at(i);
+        if value % 2 == 0 {
+            result.append(value);
+        }
+        i += 1;
+    };
+    result
+}
+
+fn last(values: @Array<u8>) -> u8 {
+    *values[values.len() - 1]
+}
+
+fn skip_first(mut values: Span<u8>) -> Span<u8> {
+    values.pop_front();
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{evens, last, skip_first};
+
+    #[test]
+    fn test_evens() {
+        let result = evens(array![1, 2, 3, 4].span());
+        assert(result.len() == 2, 'wrong length');
+        assert(*result.at(1) == 4, 'wrong last even');
+    }
+
+    #[test]
+    fn test_last() {
+        assert(last(@array![1, 2, 3]) == 3, 'wrong last');
+    }
+
+    #[test]
+    fn test_skip_first() {
+        assert(skip_first(array![1, 2].span()).len() == 1, 'first not skipped');
+    }
+}
@@ -0,0 +1,21 @@
+fn evens(values: Span<u8>) -> Array<u8> {
+    let mut result = array![];
+    let mut i = 0;
+    while i < values.len() {
+        let value = *values.at(i);
+        if value % 2 == 0 {
+            result.append(value);
+        }
+        i += 1;
+    };
+    result
+}
+
+fn last(values: @Array<u8>) -> u8 {
+    *values[values.len() - 1]
+}
+
+fn skip_first(mut values: Span<u8>) -> Span<u8> {
+    values.pop_front();
+    values
+}
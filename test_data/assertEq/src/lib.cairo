@@ -0,0 +1,19 @@
+fn simple(a: u8, b: u8) {
+    assert_eq!(a, b);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simple;
+
+    #[test]
+    fn test_equal() {
+        simple(1, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_not_equal() {
+        simple(1, 2);
+    }
+}
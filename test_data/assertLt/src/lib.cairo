@@ -0,0 +1,19 @@
+fn simple(a: u8, b: u8) {
+    assert_lt!(a, b);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simple;
+
+    #[test]
+    fn test_less() {
+        simple(1, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_equal() {
+        simple(2, 2);
+    }
+}
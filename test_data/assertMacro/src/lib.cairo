@@ -0,0 +1,14 @@
+fn simple(a: u8) {
+    assert!(a < 10, "too big");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simple;
+
+    #[test]
+    #[should_panic]
+    fn test() {
+        simple(10);
+    }
+}
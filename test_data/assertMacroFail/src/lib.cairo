@@ -0,0 +1,3 @@
+fn simple(a: u8) {
+    assert!(a < 10, "too big");
+}
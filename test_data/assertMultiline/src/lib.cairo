@@ -0,0 +1,27 @@
+fn simple(a: u8, b: Option<u8>) {
+    assert(
+        a < 10,
+        'too big'
+    );
+    match b {
+        Option::Some(_) => (),
+        Option::None => assert(false, 'none'),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simple;
+
+    #[test]
+    #[should_panic(expected: ('too big',))]
+    fn test_too_big() {
+        simple(10, Option::Some(1));
+    }
+
+    #[test]
+    #[should_panic(expected: ('none',))]
+    fn test_none() {
+        simple(1, Option::None);
+    }
+}
@@ -0,0 +1,10 @@
+fn simple(a: u8, b: Option<u8>) {
+    assert(
+        a < 10,
+        'too big'
+    );
+    match b {
+        Option::Some(_) => (),
+        Option::None => assert(false, 'none'),
+    }
+}
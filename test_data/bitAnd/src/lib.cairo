@@ -0,0 +1,13 @@
+fn simple(a: u8, b: u8) -> u8 {
+    a & b
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simple;
+
+    #[test]
+    fn test() {
+        assert(simple(12, 10) == 8, 'pass');
+    }
+}
@@ -0,0 +1,3 @@
+fn simple(a: u8, b: u8) -> u8 {
+    a & b
+}
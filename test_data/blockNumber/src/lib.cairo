@@ -0,0 +1,23 @@
+use starknet::get_block_number;
+
+fn simple() -> bool {
+    get_block_number() >= 100
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simple;
+    use starknet::testing::set_block_number;
+
+    #[test]
+    fn test_open() {
+        set_block_number(100);
+        assert(simple(), 'pass');
+    }
+
+    #[test]
+    fn test_closed() {
+        set_block_number(99);
+        assert(!simple(), 'pass');
+    }
+}
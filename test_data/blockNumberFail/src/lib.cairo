@@ -0,0 +1,5 @@
+use starknet::get_block_number;
+
+fn simple() -> bool {
+    get_block_number() >= 100
+}
@@ -0,0 +1,23 @@
+use starknet::get_block_timestamp;
+
+fn simple() -> bool {
+    get_block_timestamp() >= 100
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simple;
+    use starknet::testing::set_block_timestamp;
+
+    #[test]
+    fn test_open() {
+        set_block_timestamp(100);
+        assert(simple(), 'pass');
+    }
+
+    #[test]
+    fn test_closed() {
+        set_block_timestamp(99);
+        assert(!simple(), 'pass');
+    }
+}
@@ -0,0 +1,5 @@
+use starknet::get_block_timestamp;
+
+fn simple() -> bool {
+    get_block_timestamp() >= 100
+}
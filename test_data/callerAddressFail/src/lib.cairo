@@ -0,0 +1,5 @@
+use starknet::{ContractAddress, get_caller_address};
+
+fn simple() -> ContractAddress {
+    get_caller_address()
+}
@@ -0,0 +1,18 @@
+fn simple(a: u8) -> u8 {
+    assert(a < 10, 'too big');
+    if a == 0 {
+        1
+    } else {
+        2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simple;
+
+    #[test]
+    fn test() {
+        assert(simple(0) == 1, 'pass');
+    }
+}
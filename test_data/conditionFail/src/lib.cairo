@@ -0,0 +1,8 @@
+fn simple(a: u8) -> u8 {
+    assert(a < 10, 'too big');
+    if a == 0 {
+        1
+    } else {
+        2
+    }
+}
@@ -0,0 +1,20 @@
+use starknet::{ContractAddress, get_contract_address};
+
+fn simple() -> ContractAddress {
+    get_contract_address()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simple;
+    use starknet::{
+        contract_address_const, testing::{set_caller_address, set_contract_address}
+    };
+
+    #[test]
+    fn test() {
+        set_caller_address(contract_address_const::<1>());
+        set_contract_address(contract_address_const::<2>());
+        assert(simple() == contract_address_const::<2>(), 'pass');
+    }
+}
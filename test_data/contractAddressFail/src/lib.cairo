@@ -0,0 +1,5 @@
+use starknet::{ContractAddress, get_contract_address};
+
+fn simple() -> ContractAddress {
+    get_contract_address()
+}
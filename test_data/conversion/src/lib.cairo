@@ -0,0 +1,24 @@
+fn to_u8(value: felt252) -> u8 {
+    value.try_into().unwrap()
+}
+
+fn split(value: u256) -> (u128, u128) {
+    (value.low, value.high)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_u8, split};
+
+    #[test]
+    fn test_to_u8() {
+        assert(to_u8(200) == 200, 'wrong conversion');
+    }
+
+    #[test]
+    fn test_split() {
+        let (low, high) = split(u256 { low: 1, high: 2 });
+        assert(low == 1, 'wrong low');
+        assert(high == 2, 'wrong high');
+    }
+}
@@ -0,0 +1,7 @@
+fn to_u8(value: felt252) -> u8 {
+    value.try_into().unwrap()
+}
+
+fn split(value: u256) -> (u128, u128) {
+    (value.low, value.high)
+}
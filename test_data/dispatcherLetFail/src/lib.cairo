@@ -0,0 +1,48 @@
+#[starknet::interface]
+trait ICounter<TContractState> {
+    fn increment(ref self: TContractState);
+    fn get(self: @TContractState) -> u128;
+}
+
+#[starknet::interface]
+trait IBumper<TContractState> {
+    fn bump(ref self: TContractState, counter: starknet::ContractAddress);
+}
+
+#[starknet::contract]
+mod Counter {
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+
+    #[storage]
+    struct Storage {
+        value: u128,
+    }
+
+    #[abi(embed_v0)]
+    impl CounterImpl of super::ICounter<ContractState> {
+        fn increment(ref self: ContractState) {
+            self.value.write(self.value.read() + 1);
+        }
+
+        fn get(self: @ContractState) -> u128 {
+            self.value.read()
+        }
+    }
+}
+
+#[starknet::contract]
+mod Bumper {
+    use super::{ICounterDispatcher, ICounterDispatcherTrait};
+    use starknet::ContractAddress;
+
+    #[storage]
+    struct Storage {}
+
+    #[abi(embed_v0)]
+    impl BumperImpl of super::IBumper<ContractState> {
+        fn bump(ref self: ContractState, counter: ContractAddress) {
+            let target = ICounterDispatcher { contract_address: counter };
+            target.increment();
+        }
+    }
+}
@@ -0,0 +1,15 @@
+fn simple(a: u8, b: u8) -> u8 {
+    let mut x = a;
+    x /= b;
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simple;
+
+    #[test]
+    fn test() {
+        assert(simple(8, 2) == 4, 'pass');
+    }
+}
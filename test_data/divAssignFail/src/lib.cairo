@@ -0,0 +1,5 @@
+fn simple(a: u8, b: u8) -> u8 {
+    let mut x = a;
+    x /= b;
+    x
+}
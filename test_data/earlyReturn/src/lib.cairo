@@ -0,0 +1,27 @@
+fn clamp(a: u8, max: u8) -> u8 {
+    if a > max {
+        return max;
+    }
+    a
+}
+
+fn double_in_place(ref a: u8) {
+    a *= 2;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{clamp, double_in_place};
+
+    #[test]
+    fn test_clamp() {
+        assert(clamp(5, 10) == 5, 'should keep value');
+    }
+
+    #[test]
+    fn test_double_in_place() {
+        let mut a = 2;
+        double_in_place(ref a);
+        assert(a == 4, 'should double');
+    }
+}
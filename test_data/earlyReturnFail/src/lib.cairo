@@ -0,0 +1,10 @@
+fn clamp(a: u8, max: u8) -> u8 {
+    if a > max {
+        return max;
+    }
+    a
+}
+
+fn double_in_place(ref a: u8) {
+    a *= 2;
+}
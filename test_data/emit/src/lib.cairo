@@ -0,0 +1,39 @@
+#[starknet::contract]
+mod Counter {
+    #[storage]
+    struct Storage {}
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        Increased: Increased,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct Increased {
+        amount: u128,
+    }
+
+    #[generate_trait]
+    impl InternalImpl of InternalTrait {
+        fn increase(ref self: ContractState, amount: u128) {
+            self
+                .emit(
+                    Event::Increased(Increased { amount })
+                );
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{contract_state_for_testing, InternalImpl};
+        use starknet::{get_contract_address, testing::pop_log_raw};
+
+        #[test]
+        fn test() {
+            let mut state = contract_state_for_testing();
+            InternalImpl::increase(ref state, 2);
+            assert(pop_log_raw(get_contract_address()).is_some(), 'no event');
+        }
+    }
+}
@@ -0,0 +1,26 @@
+#[starknet::contract]
+mod Counter {
+    #[storage]
+    struct Storage {}
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        Increased: Increased,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct Increased {
+        amount: u128,
+    }
+
+    #[generate_trait]
+    impl InternalImpl of InternalTrait {
+        fn increase(ref self: ContractState, amount: u128) {
+            self
+                .emit(
+                    Event::Increased(Increased { amount })
+                );
+        }
+    }
+}
@@ -0,0 +1,13 @@
+fn simple(a: u8, b: u8, c: u8, d: u8) -> bool {
+    a == b && c == d
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simple;
+
+    #[test]
+    fn test() {
+        assert(simple(1, 1, 2, 2), 'pass');
+    }
+}
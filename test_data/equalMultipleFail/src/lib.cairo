@@ -0,0 +1,3 @@
+fn simple(a: u8, b: u8, c: u8, d: u8) -> bool {
+    a == b && c == d
+}
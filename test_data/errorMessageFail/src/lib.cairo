@@ -0,0 +1,24 @@
+fn withdraw(balance: u8, amount: u8) -> u8 {
+    assert(amount <= balance, 'Insufficient balance');
+    if amount == 0 {
+        core::panic_with_felt252('Zero amount');
+    }
+    balance - amount
+}
+
+#[cfg(test)]
+mod tests {
+    use super::withdraw;
+
+    #[test]
+    #[should_panic]
+    fn test_insufficient_balance() {
+        withdraw(1, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_amount() {
+        withdraw(1, 0);
+    }
+}
@@ -0,0 +1,14 @@
+fn to_u8(value: u16) -> Option<u8> {
+    let small: u8 = value.try_into()?;
+    Option::Some(small)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_u8;
+
+    #[test]
+    fn test_too_big() {
+        assert(to_u8(300).is_none(), 'should not fit');
+    }
+}
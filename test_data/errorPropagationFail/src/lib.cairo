@@ -0,0 +1,4 @@
+fn to_u8(value: u16) -> Option<u8> {
+    let small: u8 = value.try_into()?;
+    Option::Some(small)
+}
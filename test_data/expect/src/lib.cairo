@@ -0,0 +1,14 @@
+fn to_u8(value: u16) -> u8 {
+    value.try_into().expect('too big')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_u8;
+
+    #[test]
+    #[should_panic(expected: ('too big',))]
+    fn test_too_big() {
+        to_u8(300);
+    }
+}
@@ -0,0 +1,3 @@
+fn to_u8(value: u16) -> u8 {
+    value.try_into().expect('too big')
+}
@@ -0,0 +1,51 @@
+fn add(a: u8, b: u8) -> u8 {
+    a + b
+}
+
+fn is_big(a: u8) -> bool {
+    a > 100
+}
+
+fn values() -> Array<u8> {
+    array![1]
+}
+
+fn wrap(a: u8) -> Option<u8> {
+    Option::Some(a)
+}
+
+fn check(a: u8) {
+    assert(a < 10, 'too big');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{add, is_big, values, wrap, check};
+
+    #[test]
+    fn test_add() {
+        assert(add(2, 3) == 5, 'pass');
+    }
+
+    #[test]
+    fn test_is_big() {
+        assert(is_big(200), 'pass');
+        assert(!is_big(1), 'pass');
+    }
+
+    #[test]
+    fn test_values() {
+        assert(values().len() == 1, 'pass');
+    }
+
+    #[test]
+    fn test_wrap() {
+        assert(wrap(3).unwrap() == 3, 'pass');
+    }
+
+    #[test]
+    #[should_panic(expected: ('too big',))]
+    fn test_check() {
+        check(10);
+    }
+}
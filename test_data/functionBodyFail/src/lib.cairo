@@ -0,0 +1,19 @@
+fn add(a: u8, b: u8) -> u8 {
+    a + b
+}
+
+fn is_big(a: u8) -> bool {
+    a > 100
+}
+
+fn values() -> Array<u8> {
+    array![1]
+}
+
+fn wrap(a: u8) -> Option<u8> {
+    Option::Some(a)
+}
+
+fn check(a: u8) {
+    assert(a < 10, 'too big');
+}
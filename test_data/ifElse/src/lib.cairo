@@ -0,0 +1,28 @@
+fn is_even(a: u8) -> bool {
+    a % 2 == 0
+}
+
+fn apply(a: u8, b: u8) -> u8 {
+    let mut result = 0;
+    if is_even(a) {
+        result = a / 2;
+    } else {
+        result = b;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::apply;
+
+    #[test]
+    fn test_even() {
+        assert(apply(4, 7) == 2, 'wrong half');
+    }
+
+    #[test]
+    fn test_odd() {
+        assert(apply(3, 7) == 7, 'wrong fallback');
+    }
+}
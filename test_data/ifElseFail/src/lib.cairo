@@ -0,0 +1,13 @@
+fn is_even(a: u8) -> bool {
+    a % 2 == 0
+}
+
+fn apply(a: u8, b: u8) -> u8 {
+    let mut result = 0;
+    if is_even(a) {
+        result = a / 2;
+    } else {
+        result = b;
+    }
+    result
+}
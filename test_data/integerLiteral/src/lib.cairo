@@ -0,0 +1,15 @@
+const TEST_AMOUNT: u128 = 10000;
+
+fn fee(amount: u128) -> u128 {
+    amount * 3_u128 / 100
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fee, TEST_AMOUNT};
+
+    #[test]
+    fn test() {
+        assert(fee(TEST_AMOUNT) == 300, 'pass');
+    }
+}
@@ -0,0 +1,3 @@
+fn fee(amount: u128) -> u128 {
+    amount * 3_u128 / 100
+}
@@ -0,0 +1,46 @@
+fn sum_until_zero(values: Span<u8>) -> u8 {
+    let mut total = 0;
+    let mut i = 0;
+    loop {
+        if i == values.len() {
+            break;
+        }
+        let value = *values.at(i);
+        i += 1;
+        if value == 0 {
+            break;
+        }
+        total += value;
+    };
+    total
+}
+
+fn sum_odd(values: Span<u8>) -> u8 {
+    let mut total = 0;
+    let mut i = 0;
+    while i < values.len() {
+        let value = *values.at(i);
+        i += 1;
+        if value % 2 == 0 {
+            continue;
+        }
+        total += value;
+    };
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sum_until_zero, sum_odd};
+
+    #[test]
+    fn test_sum_until_zero() {
+        assert(sum_until_zero(array![1, 2, 0, 4].span()) == 3, 'wrong sum');
+        assert(sum_until_zero(array![1, 2].span()) == 3, 'wrong full sum');
+    }
+
+    #[test]
+    fn test_sum_odd() {
+        assert(sum_odd(array![2, 1, 3].span()) == 4, 'wrong odd sum');
+    }
+}
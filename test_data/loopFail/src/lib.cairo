@@ -0,0 +1,30 @@
+fn sum_until_zero(values: Span<u8>) -> u8 {
+    let mut total = 0;
+    let mut i = 0;
+    loop {
+        if i == values.len() {
+            break;
+        }
+        let value = *values.at(i);
+        i += 1;
+        if value == 0 {
+            break;
+        }
+        total += value;
+    };
+    total
+}
+
+fn sum_odd(values: Span<u8>) -> u8 {
+    let mut total = 0;
+    let mut i = 0;
+    while i < values.len() {
+        let value = *values.at(i);
+        i += 1;
+        if value % 2 == 0 {
+            continue;
+        }
+        total += value;
+    };
+    total
+}
@@ -0,0 +1,29 @@
+#[derive(Drop, Copy)]
+enum Tier {
+    Gold,
+    Silver,
+    Bronze,
+}
+
+fn fee(tier: Tier) -> u8 {
+    match tier {
+        Tier::Gold => {
+            let base = 5;
+            base * 2
+        }
+        Tier::Silver => 5,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fee, Tier};
+
+    #[test]
+    fn test_fee() {
+        assert(fee(Tier::Gold) == 10, 'wrong gold fee');
+        assert(fee(Tier::Silver) == 5, 'wrong silver fee');
+        assert(fee(Tier::Bronze) == 1, 'wrong bronze fee');
+    }
+}
@@ -0,0 +1,17 @@
+#[derive(Drop, Copy)]
+enum Tier {
+    Gold,
+    Silver,
+    Bronze,
+}
+
+fn fee(tier: Tier) -> u8 {
+    match tier {
+        Tier::Gold => {
+            let base = 5;
+            base * 2
+        }
+        Tier::Silver => 5,
+        _ => 1,
+    }
+}
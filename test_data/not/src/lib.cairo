@@ -0,0 +1,13 @@
+fn simple(a: bool) -> bool {
+    !a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simple;
+
+    #[test]
+    fn test() {
+        assert(simple(false), 'pass');
+    }
+}
@@ -0,0 +1,3 @@
+fn simple(a: bool) -> bool {
+    !a
+}
@@ -0,0 +1,22 @@
+fn checked_half(a: u8) -> Option<u8> {
+    if a % 2 == 0 {
+        Option::Some(a / 2)
+    } else {
+        Option::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::checked_half;
+
+    #[test]
+    fn test_even() {
+        assert(checked_half(4).unwrap() == 2, 'wrong half');
+    }
+
+    #[test]
+    fn test_odd() {
+        assert(checked_half(3).is_none(), 'should be none');
+    }
+}
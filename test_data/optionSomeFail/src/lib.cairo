@@ -0,0 +1,7 @@
+fn checked_half(a: u8) -> Option<u8> {
+    if a % 2 == 0 {
+        Option::Some(a / 2)
+    } else {
+        Option::None
+    }
+}
@@ -0,0 +1,21 @@
+fn checked_sub(a: u8, b: u8) -> Result<u8, felt252> {
+    if a < b {
+        return Result::Err('underflow');
+    }
+    Result::Ok(a - b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::checked_sub;
+
+    #[test]
+    fn test_sub() {
+        assert(checked_sub(3, 1).unwrap() == 2, 'wrong difference');
+    }
+
+    #[test]
+    fn test_underflow() {
+        assert(checked_sub(1, 3).is_err(), 'should underflow');
+    }
+}
@@ -0,0 +1,6 @@
+fn checked_sub(a: u8, b: u8) -> Result<u8, felt252> {
+    if a < b {
+        return Result::Err('underflow');
+    }
+    Result::Ok(a - b)
+}
@@ -0,0 +1,16 @@
+fn clamp(a: u8, max: u8) -> u8 {
+    if a > max {
+        return max;
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::clamp;
+
+    #[test]
+    fn test_clamp() {
+        assert(clamp(20, 10) == 10, 'should clamp');
+    }
+}
@@ -0,0 +1,6 @@
+fn clamp(a: u8, max: u8) -> u8 {
+    if a > max {
+        return max;
+    }
+    a
+}
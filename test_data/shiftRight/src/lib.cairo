@@ -0,0 +1,13 @@
+fn simple(a: u8) -> u8 {
+    a >> 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simple;
+
+    #[test]
+    fn test() {
+        assert(simple(6) == 3, 'pass');
+    }
+}
@@ -0,0 +1,3 @@
+fn simple(a: u8) -> u8 {
+    a >> 1
+}
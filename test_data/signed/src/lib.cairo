@@ -0,0 +1,38 @@
+fn price_change(old: i128, new: i128) -> i128 {
+    let delta: i128 = new - old;
+    delta
+}
+
+fn magnitude(value: i128) -> i128 {
+    if value < 0 {
+        -value
+    } else {
+        value
+    }
+}
+
+fn discount(price: i128) -> i128 {
+    let fee: i128 = -5;
+    price + fee
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{price_change, magnitude, discount};
+
+    #[test]
+    fn test_price_change() {
+        assert(price_change(10, 15) == 5, 'wrong change');
+    }
+
+    #[test]
+    fn test_magnitude() {
+        assert(magnitude(-3) == 3, 'wrong negative magnitude');
+        assert(magnitude(4) == 4, 'wrong positive magnitude');
+    }
+
+    #[test]
+    fn test_discount() {
+        assert(discount(100) == 95, 'wrong discount');
+    }
+}
@@ -0,0 +1,17 @@
+fn price_change(old: i128, new: i128) -> i128 {
+    let delta: i128 = new - old;
+    delta
+}
+
+fn magnitude(value: i128) -> i128 {
+    if value < 0 {
+        -value
+    } else {
+        value
+    }
+}
+
+fn discount(price: i128) -> i128 {
+    let fee: i128 = -5;
+    price + fee
+}
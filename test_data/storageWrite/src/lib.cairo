@@ -0,0 +1,38 @@
+#[starknet::interface]
+trait ICounter<TContractState> {
+    fn increase(ref self: TContractState, amount: u128);
+    fn get(self: @TContractState) -> u128;
+}
+
+#[starknet::contract]
+mod Counter {
+    use starknet::storage::{StoragePointerReadAccess, StoragePointerWriteAccess};
+
+    #[storage]
+    struct Storage {
+        value: u128,
+    }
+
+    #[abi(embed_v0)]
+    impl CounterImpl of super::ICounter<ContractState> {
+        fn increase(ref self: ContractState, amount: u128) {
+            self.value.write(self.value.read() + amount);
+        }
+
+        fn get(self: @ContractState) -> u128 {
+            self.value.read()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{contract_state_for_testing, CounterImpl};
+
+        #[test]
+        fn test() {
+            let mut state = contract_state_for_testing();
+            CounterImpl::increase(ref state, 2);
+            assert(CounterImpl::get(@state) == 2, 'pass');
+        }
+    }
+}
@@ -0,0 +1,13 @@
+fn simple() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::simple;
+
+    #[test]
+    fn test() {
+        assert(simple(), 'pass');
+    }
+}
@@ -0,0 +1,3 @@
+fn simple() -> bool {
+    true
+}
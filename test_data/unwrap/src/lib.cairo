@@ -0,0 +1,19 @@
+fn value_or_panic(value: Option<u8>) -> u8 {
+    value.unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::value_or_panic;
+
+    #[test]
+    fn test_some() {
+        assert(value_or_panic(Option::Some(3)) == 3, 'wrong value');
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_none() {
+        value_or_panic(Option::None);
+    }
+}
@@ -0,0 +1,3 @@
+fn value_or_panic(value: Option<u8>) -> u8 {
+    value.unwrap()
+}